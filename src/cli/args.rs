@@ -1,31 +1,105 @@
-use clap::{Parser, ValueEnum};
+use std::path::PathBuf;
+use clap::{Parser, Subcommand, ValueEnum};
+use lbdt::compare::filter::{is_known_property_pattern, ObjectFilter};
 
 #[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
+#[command(version, about, long_about = None, args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct Args {
-    #[arg(short, long, short = 'l', help = "The left database URL")]
-    pub left: String,
+    #[command(subcommand)]
+    pub command: Option<Command>,
 
-    #[arg(short, long, short = 'r', help = "The right database URL")]
-    pub right: String,
+    #[arg(short, long, required = true, short = 'l', help = "The left database URL or snapshot file")]
+    pub left: Option<String>,
 
-    #[arg(short, long, required = true, short = 's', help = "Schema to compare")]
+    #[arg(short, long, required = true, short = 'r', help = "The right database URL or snapshot file")]
+    pub right: Option<String>,
+
+    #[arg(short, long, required_unless_present = "map_schema", short = 's', help = "Schema to compare")]
     pub schema: Vec<String>,
 
+    #[arg(long, value_name = "LEFT=RIGHT", value_parser = parse_schema_mapping, help = "Compare schema LEFT on the left with schema RIGHT on the right")]
+    pub map_schema: Vec<(String, String)>,
+
+    #[arg(long, value_name = "KIND:PATTERN", help = "Compare only objects of KIND whose names match the glob PATTERN")]
+    pub include: Vec<ObjectFilter>,
+
+    #[arg(long, value_name = "KIND:PATTERN", help = "Leave out objects of KIND whose names match the glob PATTERN")]
+    pub exclude: Vec<ObjectFilter>,
+
     #[arg(short, long, short = 'w', help = "Ignore routine whitespace differences")]
     pub ignore_whitespace: bool,
 
+    #[arg(long, help = "Ignore layout, case, comment and redundant parenthesis differences in SQL definitions")]
+    pub normalize_sql: bool,
+
     #[arg(short, long, short = 'o', help = "Ignore column ordering differences")]
     pub ignore_column_ordinal: bool,
 
     #[arg(short, long, short = 'p', help = "Ignore privilege changes")]
     pub ignore_privileges: bool,
 
+    #[arg(long, value_name = "PATH", value_parser = parse_property_pattern, help = "Ignore changes to properties whose path, such as table.column.collation_name, matches the glob PATH")]
+    pub ignore_property: Vec<String>,
+
+    #[arg(long, help = "Compare the full definition of routines rather than only their body")]
+    pub compare_routine_definitions: bool,
+
+    #[arg(long, help = "Ignore objects that belong to an extension")]
+    pub ignore_extension_objects: bool,
+
+    #[arg(long, help = "Compare partitions as part of their partitioned table")]
+    pub collapse_partitions: bool,
+
+    #[arg(long, help = "Compare whether columns are defined locally or inherited")]
+    pub distinguish_inherited_columns: bool,
+
     #[arg(short, long, short = 'v', help = "Show matches")]
     pub verbose: bool,
 
     #[arg(short, long, short = 'c', alias = "colour", help = "Use colour", default_value = "auto")]
     pub color: Colouring,
+
+    #[arg(short, long, short = 'f', help = "Output format", default_value = "text")]
+    pub format: Format,
+
+    #[arg(long, conflicts_with = "emit_sql", help = "Follow the report with a tally of changes per kind of object")]
+    pub summary: bool,
+
+    #[arg(long, conflicts_with = "emit_sql", help = "Show only the tally of changes per kind of object")]
+    pub summary_only: bool,
+
+    #[arg(long, conflicts_with = "format", help = "Print SQL that migrates the left database to the right")]
+    pub emit_sql: bool,
+}
+
+fn parse_schema_mapping(mapping: &str) -> Result<(String, String), String> {
+    match mapping.split_once('=') {
+        Some((left, right)) if !left.is_empty() && !right.is_empty() => Ok((String::from(left), String::from(right))),
+        _ => Err(format!("'{}' is not of the form LEFT=RIGHT", mapping)),
+    }
+}
+
+fn parse_property_pattern(pattern: &str) -> Result<String, String> {
+    if is_known_property_pattern(pattern) {
+        Ok(String::from(pattern))
+    } else {
+        Err(format!("'{}' matches no property path, such as table.column.collation_name", pattern))
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    #[command(about = "Save the schemas of a database to a snapshot file")]
+    Snapshot {
+        #[arg(short, long, short = 'u', help = "The database URL")]
+        url: String,
+
+        #[arg(short, long, required = true, short = 's', help = "Schema to capture")]
+        schema: Vec<String>,
+
+        #[arg(short, long, short = 'o', help = "The snapshot file to write")]
+        output: PathBuf,
+    },
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
@@ -34,3 +108,9 @@ pub enum Colouring {
     Always,
     Never,
 }
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
+pub enum Format {
+    Text,
+    Json,
+}
@@ -0,0 +1,48 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+pub const EXIT_IDENTICAL: i32 = 0;
+pub const EXIT_DIFFERENCES: i32 = 1;
+pub const EXIT_ERROR: i32 = 2;
+pub const EXIT_CONNECTION_ERROR: i32 = 3;
+pub const EXIT_QUERY_ERROR: i32 = 4;
+
+/// An error that stops a run, classified by the exit status it is reported with.
+#[derive(Debug)]
+pub enum Failure {
+    Connection(Box<dyn Error>),
+    Query(Box<dyn Error>),
+    Other(Box<dyn Error>),
+}
+
+impl Failure {
+    pub fn connection<E: Into<Box<dyn Error>>>(error: E) -> Failure {
+        Failure::Connection(error.into())
+    }
+
+    pub fn query<E: Into<Box<dyn Error>>>(error: E) -> Failure {
+        Failure::Query(error.into())
+    }
+
+    pub fn other<E: Into<Box<dyn Error>>>(error: E) -> Failure {
+        Failure::Other(error.into())
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Failure::Connection(_) => EXIT_CONNECTION_ERROR,
+            Failure::Query(_) => EXIT_QUERY_ERROR,
+            Failure::Other(_) => EXIT_ERROR,
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Connection(error) => write!(f, "connection failed: {}", error),
+            Failure::Query(error) => write!(f, "query failed: {}", error),
+            Failure::Other(error) => write!(f, "{}", error),
+        }
+    }
+}
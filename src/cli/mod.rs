@@ -1,41 +1,79 @@
 mod args;
+mod failure;
 
-use std::process;
+use std::path::Path;
 use clap::{Parser};
 use colored::Colorize;
-use sqlx::Error;
-
-use crate::{compare, db};
-use crate::cli::args::{Args, Colouring::Always, Colouring::Never};
-use crate::compare::report::{HasChanges, Report};
-use crate::compare::report::privilege::PrivilegeComparison;
-use crate::compare::report::privilege::PrivilegeComparison::{PrivilegeAdded, PrivilegeMaintained, PrivilegeRemoved};
-use crate::compare::report::property::PropertyComparison;
-use crate::compare::report::property::PropertyComparison::{PropertyChanged, PropertyUnchanged};
-use crate::compare::report::routine::RoutineComparison;
-use crate::compare::report::routine::RoutineComparison::{RoutineAdded, RoutineMaintained, RoutineRemoved};
-use crate::compare::report::schema::SchemaComparison;
-use crate::compare::report::schema::SchemaComparison::{SchemaAdded, SchemaMaintained, SchemaMissing, SchemaRemoved};
-use crate::compare::report::sequence::SequenceComparison;
-use crate::compare::report::sequence::SequenceComparison::{SequenceAdded, SequenceMaintained, SequenceRemoved};
-use crate::compare::report::table::TableComparison;
-use crate::compare::report::table::TableComparison::{TableAdded, TableMaintained, TableRemoved};
-use crate::compare::report::column::ColumnComparison;
-use crate::compare::report::column::ColumnComparison::{ColumnAdded, ColumnMaintained, ColumnRemoved};
-use crate::compare::report::index::IndexComparison;
-use crate::compare::report::index::IndexComparison::{IndexAdded, IndexMaintained, IndexRemoved};
-use crate::compare::report::table_constraint::TableConstraintComparison;
-use crate::compare::report::table_constraint::TableConstraintComparison::{ConstraintAdded, ConstraintMaintained, ConstraintRemoved};
-use crate::compare::report::table_trigger::TableTriggerComparison::{TriggerAdded, TriggerMaintained, TriggerRemoved};
-use crate::compare::report::table_trigger::{TableTriggerComparison};
-use crate::compare::report::view::ViewComparison;
-use crate::compare::report::view::ViewComparison::ViewMaintained;
+use itertools::Itertools;
+use serde::Serialize;
+
+use lbdt::{compare, db};
+use crate::cli::args::{Args, Colouring::Always, Colouring::Never, Command, Format};
+use crate::cli::failure::{Failure, EXIT_DIFFERENCES, EXIT_IDENTICAL};
+use lbdt::compare::report::{HasChanges, Report};
+use lbdt::compare::report::privilege::PrivilegeComparison;
+use lbdt::compare::report::privilege::PrivilegeComparison::{PrivilegeAdded, PrivilegeMaintained, PrivilegeRemoved};
+use lbdt::compare::report::property::PropertyComparison;
+use lbdt::compare::report::property::PropertyComparison::{PropertyChanged, PropertyUnchanged};
+use lbdt::compare::report::routine::RoutineComparison;
+use lbdt::compare::report::routine::RoutineComparison::{RoutineAdded, RoutineMaintained, RoutineRemoved};
+use lbdt::compare::report::schema::SchemaComparison;
+use lbdt::compare::report::schema::SchemaComparison::{SchemaAdded, SchemaMaintained, SchemaMissing, SchemaRemoved};
+use lbdt::compare::report::sequence::SequenceComparison;
+use lbdt::compare::report::sequence::SequenceComparison::{SequenceAdded, SequenceMaintained, SequenceRemoved};
+use lbdt::compare::report::summary::Summary;
+use lbdt::compare::report::table::TableComparison;
+use lbdt::compare::report::table::TableComparison::{TableAdded, TableMaintained, TableRemoved};
+use lbdt::compare::report::column::ColumnComparison;
+use lbdt::compare::report::column::ColumnComparison::{ColumnAdded, ColumnMaintained, ColumnRemoved};
+use lbdt::compare::report::database::DatabaseComparison;
+use lbdt::compare::report::extension::ExtensionComparison;
+use lbdt::compare::report::extension::ExtensionComparison::{ExtensionAdded, ExtensionMaintained, ExtensionRemoved};
+use lbdt::compare::report::index::IndexComparison;
+use lbdt::compare::report::index::IndexComparison::{IndexAdded, IndexMaintained, IndexRemoved};
+use lbdt::compare::report::partition::PartitionComparison;
+use lbdt::compare::report::partition::PartitionComparison::{PartitionAdded, PartitionMaintained, PartitionRemoved};
+use lbdt::compare::report::policy::PolicyComparison;
+use lbdt::compare::report::policy::PolicyComparison::{PolicyAdded, PolicyMaintained, PolicyRemoved};
+use lbdt::compare::report::materialized_view::MaterializedViewComparison;
+use lbdt::compare::report::materialized_view::MaterializedViewComparison::{MaterializedViewAdded, MaterializedViewMaintained, MaterializedViewRemoved};
+use lbdt::compare::report::table_constraint::TableConstraintComparison;
+use lbdt::compare::report::table_constraint::TableConstraintComparison::{ConstraintAdded, ConstraintMaintained, ConstraintRemoved};
+use lbdt::compare::report::table_trigger::TableTriggerComparison::{TriggerAdded, TriggerMaintained, TriggerRemoved};
+use lbdt::compare::report::table_trigger::{TableTriggerComparison};
+use lbdt::compare::report::user_type::UserTypeComparison;
+use lbdt::compare::report::user_type::UserTypeComparison::{UserTypeAdded, UserTypeMaintained, UserTypeRemoved};
+use lbdt::compare::report::user_type_attribute::UserTypeAttributeComparison;
+use lbdt::compare::report::user_type_attribute::UserTypeAttributeComparison::{AttributeAdded, AttributeMaintained, AttributeRemoved};
+use lbdt::compare::report::view::ViewComparison;
+use lbdt::compare::report::view::ViewComparison::{ViewAdded, ViewMaintained, ViewRemoved};
+use lbdt::db::snapshot::Snapshot;
+use lbdt::db::source::SchemaSource;
+use lbdt::migration::Migration;
 
 const COLOUR_ADDED: colored::Color = colored::Color::Green;
 const COLOUR_CHANGED: colored::Color= colored::Color::Yellow;
 const COLOUR_MISSING: colored::Color = colored::Color::Magenta;
 const COLOUR_REMOVED: colored::Color = colored::Color::Red;
+const COLOUR_SECURITY: colored::Color = colored::Color::BrightRed;
+const COLOUR_DIFF_HUNK: colored::Color = colored::Color::Cyan;
+
+const JSON_FORMAT_VERSION: u32 = 1;
+
+// how the text report shows a property without a value
+const ABSENT_VALUE: &str = "<none>";
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    format_version: u32,
+    change_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<Summary>,
+    #[serde(flatten)]
+    report: Option<&'a DatabaseComparison>,
+}
 
+#[allow(clippy::upper_case_acronyms)]
 pub struct CLI {
     args: Args,
 }
@@ -53,37 +91,158 @@ impl CLI {
         CLI { args }
     }
     
-    pub async fn run(&self) -> Result<i32, Error> {
-        let left_db = db::Database::connect(self.args.left.as_str()).await?;
-        let right_db = db::Database::connect(self.args.right.as_str()).await?;
+    pub async fn run(&self) -> Result<i32, Failure> {
+        match &self.args.command {
+            Some(Command::Snapshot { url, schema, output }) => self.snapshot(url, schema, output).await,
+            None => self.compare().await,
+        }
+    }
+
+    async fn snapshot(&self, url: &str, schema_names: &[String], output: &Path) -> Result<i32, Failure> {
+        let database = db::Database::connect(url).await.map_err(Failure::connection)?;
+        let snapshot = Snapshot::capture(&database, schema_names).await.map_err(Failure::query)?;
+
+        snapshot.write(output).map_err(Failure::other)?;
+
+        Ok(EXIT_IDENTICAL)
+    }
+
+    async fn compare(&self) -> Result<i32, Failure> {
+        if let Some((left_name, _)) = self.args.map_schema.iter().duplicates_by(|(left_name, _)| left_name).next() {
+            return Err(Failure::other(format!("schema '{}' is mapped more than once", left_name)));
+        }
+
+        let left_db = open_database(self.args.left.as_deref().unwrap_or_default()).await?;
+        let right_db = open_database(self.args.right.as_deref().unwrap_or_default()).await?;
+
+        let options = compare::Options {
+            ignore_whitespace: self.args.ignore_whitespace,
+            ignore_column_ordinal: self.args.ignore_column_ordinal,
+            ignore_privileges: self.args.ignore_privileges,
+            collapse_partitions: self.args.collapse_partitions,
+            distinguish_inherited_columns: self.args.distinguish_inherited_columns,
+            ignore_extension_objects: self.args.ignore_extension_objects,
+            compare_routine_definitions: self.args.compare_routine_definitions,
+            normalize_sql: self.args.normalize_sql,
+            include: self.args.include.clone(),
+            exclude: self.args.exclude.clone(),
+            ignore_properties: self.args.ignore_property.clone(),
+            schema_map: self.args.map_schema.iter().cloned().collect(),
+        };
+
+        let mut schema_names = self.args.schema.clone();
+        for (left_name, _) in &self.args.map_schema {
+            if !schema_names.contains(left_name) {
+                schema_names.push(left_name.clone());
+            }
+        }
+
+        let comparer = compare::Comparer::new(left_db, right_db, options);
+
+        let report = comparer.compare(schema_names).await.map_err(Failure::query)?;
+
+        if self.args.emit_sql {
+            print!("{}", Migration::new(&report));
+        } else {
+            match self.args.format {
+                Format::Text => {
+                    if !self.args.summary_only {
+                        self.render_extension_report(&report.extensions);
+                        self.render_schema_report(&report.schemas);
+                    }
+
+                    if self.args.summary || self.args.summary_only {
+                        self.render_summary(&Summary::new(&report));
+                    }
+
+                    self.render_change_count(&report);
+                },
+                Format::Json => self.render_json_report(&report).map_err(Failure::other)?,
+            }
+        }
+
+        Ok(if report.has_changes() { EXIT_DIFFERENCES } else { EXIT_IDENTICAL })
+    }
+
+    fn render_json_report(&self, report: &DatabaseComparison) -> Result<(), serde_json::Error> {
+        let document = JsonReport {
+            format_version: JSON_FORMAT_VERSION,
+            change_count: report.change_count(),
+            summary: (self.args.summary || self.args.summary_only).then(|| Summary::new(report)),
+            report: (!self.args.summary_only).then_some(report),
+        };
+
+        println!("{}", serde_json::to_string_pretty(&document)?);
+
+        Ok(())
+    }
+
+    fn render_summary(&self, summary: &Summary) {
+        println!("Summary:");
 
-        let mut comparer = compare::Comparer::new(
-            left_db,
-            right_db,
-            self.args.ignore_whitespace,
-            self.args.ignore_column_ordinal,
-            self.args.ignore_privileges);
+        let tallies: Vec<_> = summary.tallies().into_iter()
+            .filter(|(_, tally)| tally.total() > 0 || self.args.verbose)
+            .collect();
 
-        let mut differences = 0;
+        if tallies.is_empty() {
+            println!("  no differences");
+        }
 
-        let report = comparer.compare(self.args.schema.clone()).await?;
-        differences += self.render_schema_report(report);
+        for (kind, tally) in tallies {
+            println!("  {}: {} added, {} removed, {} changed",
+                     kind,
+                     tally.added.to_string().color(COLOUR_ADDED),
+                     tally.removed.to_string().color(COLOUR_REMOVED),
+                     tally.changed.to_string().color(COLOUR_CHANGED));
+        }
+    }
 
-        process::exit(differences);
+    fn render_change_count(&self, report: &DatabaseComparison) {
+        match report.change_count() {
+            0 if self.args.verbose => println!("No differences"),
+            0 => (),
+            1 => println!("1 difference"),
+            count => println!("{} differences", count),
+        }
     }
 
-    fn render_schema_report(&self, report: Report<SchemaComparison>) -> i32{
-        let mut differences = 0;
+    fn render_extension_report(&self, report: &Report<ExtensionComparison>) {
+        for extension in &report.entries {
+            match extension {
+                ExtensionMaintained { extension_name, properties } => {
+                    let has_changes = extension.has_changes();
+
+                    if has_changes {
+                        let message = format!("Extension '{}':", extension_name);
+                        println!("{}", message.color(COLOUR_CHANGED));
+                    } else if self.args.verbose {
+                        println!("Extension '{}': unchanged", extension_name);
+                    }
+
+                    if has_changes || self.args.verbose {
+                        self.render_property_report(properties, 1);
+                    }
+                },
+                ExtensionAdded { extension_name, .. } => {
+                    let message = format!("Extension '{}': added", extension_name);
+                    println!("{}", message.color(COLOUR_ADDED));
+                }
+                ExtensionRemoved { extension_name } => {
+                    let message = format!("Extension '{}': removed", extension_name);
+                    println!("{}", message.color(COLOUR_REMOVED));
+                }
+            }
+        }
+    }
 
+    fn render_schema_report(&self, report: &Report<SchemaComparison>) {
         for schema in &report.entries {
             match schema {
                 SchemaMissing { schema_name } => {
                     let message = format!("Schema '{}': missing in both", schema_name);
                     println!("{}", message.color(COLOUR_MISSING));
-                    
-                    differences += 1;
                 },
-                SchemaMaintained { schema_name, properties, routines, sequences, tables, views } => {
+                SchemaMaintained { schema_name, properties, materialized_views, routines, sequences, tables, types, views } => {
                     let has_changes = schema.has_changes();
                     
                     if has_changes {
@@ -94,56 +253,62 @@ impl CLI {
                     }
                     
                     if has_changes || self.args.verbose {
-                        differences += self.render_property_report(properties, 1);
-                        differences += self.render_routine_report(routines);
-                        differences += self.render_sequence_report(sequences);
-                        differences += self.render_table_report(tables);
-                        differences += self.render_view_report(views);
+                        self.render_property_report(properties, 1);
+                        self.render_routine_report(routines);
+                        self.render_sequence_report(sequences);
+                        self.render_table_report(tables);
+                        self.render_user_type_report(types);
+                        self.render_view_report(views);
+                        self.render_materialized_view_report(materialized_views);
+                        self.render_row_security_warnings(tables);
                     }
                 }
                 SchemaAdded { schema_name } => {
                     let message = format!("Schema '{}': added", schema_name);
                     println!("{}", message.color(COLOUR_ADDED));
-
-                    differences += 1;
                 },
                 SchemaRemoved { schema_name } => {
                     let message = format!("Schema '{}': removed", schema_name);
                     println!("{}", message.red());
-
-                    differences += 1;
                 }
             }
         }
-        
-        differences
     }
 
-    fn render_property_report(&self, report: &Report<PropertyComparison>, depth: usize) -> i32 {
-        let mut differences = 0;
+    fn render_property_report(&self, report: &Report<PropertyComparison>, depth: usize) {
         let margin = str::repeat("  ", depth);
 
         for property in &report.entries {
             match property {
-                PropertyChanged { property_name, left_value, right_value } => {
-                    let message = format!("{}Property '{}': changed from '{}' to '{}'", margin, property_name, left_value.color(COLOUR_REMOVED), right_value.color(COLOUR_ADDED));
+                PropertyChanged { property_name, diff: Some(diff), .. } => {
+                    let message = format!("{}Property '{}': changed", margin, property_name);
                     println!("{}", message.color(COLOUR_CHANGED));
 
-                    differences += 1;
+                    for line in diff.lines() {
+                        let line = match line.chars().next() {
+                            Some('+') => line.color(COLOUR_ADDED),
+                            Some('-') => line.color(COLOUR_REMOVED),
+                            Some('@') => line.color(COLOUR_DIFF_HUNK),
+                            _ => line.normal(),
+                        };
+
+                        println!("{}  {}", margin, line);
+                    }
+                }
+                PropertyChanged { property_name, left_value, right_value, .. } => {
+                    let message = format!("{}Property '{}': changed from '{}' to '{}'", margin, property_name, left_value.as_deref().unwrap_or(ABSENT_VALUE).color(COLOUR_REMOVED), right_value.as_deref().unwrap_or(ABSENT_VALUE).color(COLOUR_ADDED));
+                    println!("{}", message.color(COLOUR_CHANGED));
                 }
                 PropertyUnchanged { property_name, value } => {
                     if self.args.verbose {
-                        println!("{}Property '{}': unchanged at '{}'", margin, property_name, value);
+                        println!("{}Property '{}': unchanged at '{}'", margin, property_name, value.as_deref().unwrap_or(ABSENT_VALUE));
                     }
                 },
             }
         }
-        
-        differences
     }
 
-    fn render_privilege_report(&self, report: &Report<PrivilegeComparison>, depth: usize) -> i32 {
-        let mut differences = 0;
+    fn render_privilege_report(&self, report: &Report<PrivilegeComparison>, depth: usize) {
         let margin = str::repeat("  ", depth);
 
         for privilege in &report.entries {
@@ -151,14 +316,10 @@ impl CLI {
                 PrivilegeAdded { privilege_name, grantor, grantee } => {
                     let message = format!("{}Privilege '{}' ({}->{}): added", margin, privilege_name, grantor, grantee);
                     println!("{}", message.color(COLOUR_ADDED));
-                    
-                    differences += 1;
                 }
                 PrivilegeRemoved { privilege_name, grantor, grantee } => {
                     let message = format!("{}Privilege '{}' ({}->{}): removed", margin, privilege_name, grantor, grantee);
                     println!("{}", message.color(COLOUR_REMOVED));
-
-                    differences += 1;
                 },
                 PrivilegeMaintained { privilege_name, grantor, grantee } => {
                     if self.args.verbose {
@@ -167,16 +328,12 @@ impl CLI {
                 },
             }
         }
-
-        differences
     }
 
-    fn render_routine_report(&self, report: &Report<RoutineComparison>) -> i32 {
-        let mut differences = 0;
-
+    fn render_routine_report(&self, report: &Report<RoutineComparison>) {
         for routine in &report.entries {
             match routine {
-                RoutineMaintained { routine_signature, properties, privileges } => {
+                RoutineMaintained { routine_signature, properties, privileges, .. } => {
                     let has_changes = routine.has_changes();
                     
                     if has_changes {
@@ -188,31 +345,23 @@ impl CLI {
                     }
                     
                     if has_changes || self.args.verbose {
-                        differences += self.render_property_report(&properties, 2);
-                        differences += self.render_privilege_report(&privileges, 2);
+                        self.render_property_report(properties, 2);
+                        self.render_privilege_report(privileges, 2);
                     }
                 },
-                RoutineAdded { routine_signature } => {
+                RoutineAdded { routine_signature, .. } => {
                     let message = format!("  Routine '{}': added", routine_signature);
                     println!("{}", message.color(COLOUR_ADDED));
-
-                    differences += 1;
                 }
                 RoutineRemoved { routine_signature } => {
                     let message = format!("  Routine '{}': removed", routine_signature);
                     println!("{}", message.color(COLOUR_REMOVED));
-
-                    differences += 1;
                 }
             }
         }
-        
-        differences
     }
     
-    fn render_sequence_report(&self, report: &Report<SequenceComparison>) -> i32 {
-        let mut differences = 0;
-
+    fn render_sequence_report(&self, report: &Report<SequenceComparison>) {
         for sequence in &report.entries {
             match sequence {
                 SequenceMaintained { sequence_name, properties } => {
@@ -226,33 +375,25 @@ impl CLI {
                     }
                     
                     if has_changes || self.args.verbose {
-                        differences += self.render_property_report(&properties, 2);
+                        self.render_property_report(properties, 2);
                     }
                 },
-                SequenceAdded { sequence_name } => {
+                SequenceAdded { sequence_name, .. } => {
                     let message = format!("  Sequence '{}': added", sequence_name);
                     println!("{}", message.color(COLOUR_ADDED));
-
-                    differences += 1;
                 }
                 SequenceRemoved { sequence_name } => {
                     let message = format!("  Sequence '{}': removed", sequence_name);
                     println!("{}", message.color(COLOUR_REMOVED));
-                    
-                    differences += 1;
                 }
             }
         }
-
-        differences
     }
     
-    fn render_table_report(&self, report: &Report<TableComparison>) -> i32 {
-        let mut differences = 0;
-
+    fn render_table_report(&self, report: &Report<TableComparison>) {
         for table in &report.entries {
             match table {
-                TableMaintained { table_name, columns, constraints, indices, privileges, properties, triggers } => {
+                TableMaintained { table_name, columns, constraints, indices, partitions, policies, privileges, properties, triggers } => {
                     let has_changes = table.has_changes();
                     
                     if has_changes {
@@ -263,35 +404,29 @@ impl CLI {
                     }
                     
                     if has_changes || self.args.verbose {
-                        differences += self.render_property_report(&properties, 2);
-                        differences += self.render_table_column_report(&columns);
-                        differences += self.render_privilege_report(&privileges, 2);
-                        differences += self.render_table_constraint_report(&constraints);
-                        differences += self.render_table_trigger_report(&triggers);
-                        differences += self.render_table_index_report(&indices);
+                        self.render_property_report(properties, 2);
+                        self.render_table_column_report(columns);
+                        self.render_privilege_report(privileges, 2);
+                        self.render_table_constraint_report(constraints);
+                        self.render_table_trigger_report(triggers);
+                        self.render_table_index_report(indices);
+                        self.render_table_policy_report(policies);
+                        self.render_table_partition_report(partitions);
                     }
                 },
-                TableAdded { table_name } => {
+                TableAdded { table_name, .. } => {
                     let message = format!("  Table '{}': added", table_name);
                     println!("{}", message.color(COLOUR_ADDED));
-
-                    differences += 1;
                 }
-                TableRemoved { table_name } => {
+                TableRemoved { table_name, .. } => {
                     let message = format!("  Table '{}': removed", table_name);
                     println!("{}", message.color(COLOUR_REMOVED));
-                    
-                    differences += 1;
                 }
             }
         }
-
-        differences
     }
     
-    fn render_table_column_report(&self, report: &Report<ColumnComparison>) -> i32 {
-        let mut differences = 0;
-
+    fn render_table_column_report(&self, report: &Report<ColumnComparison>) {
         for column in &report.entries {
             match column {
                 ColumnMaintained { column_name, properties, privileges  } => {
@@ -305,31 +440,23 @@ impl CLI {
                     }
                     
                     if has_changes || self.args.verbose {
-                        differences += self.render_property_report(&properties, 3);
-                        differences += self.render_privilege_report(&privileges, 3);
+                        self.render_property_report(properties, 3);
+                        self.render_privilege_report(privileges, 3);
                     }
                 },
-                ColumnAdded { column_name } => {
+                ColumnAdded { column_name, .. } => {
                     let message = format!("    Column '{}': added", column_name);
                     println!("{}", message.color(COLOUR_ADDED));
-
-                    differences += 1;
                 }
                 ColumnRemoved { column_name } => {
                     let message = format!("    Column '{}': removed", column_name);
                     println!("{}", message.color(COLOUR_REMOVED));
-
-                    differences += 1;
                 }
             }
         }
-
-        differences
     }
     
-    fn render_table_constraint_report(&self, report: &Report<TableConstraintComparison>) -> i32 {
-        let mut differences = 0;
-
+    fn render_table_constraint_report(&self, report: &Report<TableConstraintComparison>) {
         for constraint in &report.entries {
             match constraint {
                 ConstraintMaintained { constraint_name, properties } => {
@@ -343,30 +470,22 @@ impl CLI {
                     }
                     
                     if has_changes || self.args.verbose {
-                        differences += self.render_property_report(&properties, 3);
+                        self.render_property_report(properties, 3);
                     }
                 },
-                ConstraintAdded { constraint_name } => {
+                ConstraintAdded { constraint_name, .. } => {
                     let message = format!("    Constraint '{}': added", constraint_name);
                     println!("{}", message.color(COLOUR_ADDED));
-                    
-                    differences += 1;
                 }
                 ConstraintRemoved { constraint_name } => {
                     let message = format!("    Constraint '{}': removed", constraint_name);
                     println!("{}", message.color(COLOUR_REMOVED));
-
-                    differences += 1;
                 }
             }
         }
-
-        differences
     }
 
-    fn render_table_index_report(&self, report: &Report<IndexComparison>) -> i32 {
-        let mut differences = 0;
-
+    fn render_table_index_report(&self, report: &Report<IndexComparison>) {
         for index in &report.entries {
             match index {
                 IndexMaintained { index_name, properties } => {
@@ -380,33 +499,25 @@ impl CLI {
                     }
 
                     if has_changes || self.args.verbose {
-                        differences += self.render_property_report(&properties, 3);
+                        self.render_property_report(properties, 3);
                     }
                 },
-                IndexAdded { index_name } => {
+                IndexAdded { index_name, .. } => {
                     let message = format!("    Index '{}': added", index_name);
                     println!("{}", message.color(COLOUR_ADDED));
-
-                    differences += 1;
                 }
                 IndexRemoved { index_name } => {
                     let message = format!("    Index '{}': removed", index_name);
                     println!("{}", message.color(COLOUR_REMOVED));
-
-                    differences += 1;
                 }
             }
         }
-
-        differences
     }
 
-    fn render_table_trigger_report(&self, report: &Report<TableTriggerComparison>) -> i32 {
-        let mut differences = 0;
-
+    fn render_table_trigger_report(&self, report: &Report<TableTriggerComparison>) {
         for trigger in &report.entries {
             match trigger {
-                TriggerMaintained { trigger_name, event_manipulation, properties } => {
+                TriggerMaintained { trigger_name, event_manipulation, properties, .. } => {
                     let has_changes = trigger.has_changes();
                     
                     if has_changes {
@@ -417,30 +528,155 @@ impl CLI {
                     }
                     
                     if has_changes || self.args.verbose {
-                        differences += self.render_property_report(&properties, 3);
+                        self.render_property_report(properties, 3);
                     }
                 },
-                TriggerAdded { trigger_name, event_manipulation } => {
+                TriggerAdded { trigger_name, event_manipulation, .. } => {
                     let message = format!("    Trigger '{}' ({}): added", trigger_name, event_manipulation);
                     println!("{}", message.color(COLOUR_ADDED));
-                    
-                    differences += 1;
                 }
                 TriggerRemoved { trigger_name, event_manipulation } => {
                     let message = format!("    Trigger '{}' ({}): removed", trigger_name, event_manipulation);
                     println!("{}", message.color(COLOUR_REMOVED));
-                    
-                    differences += 1;
                 }
             }
         }
+    }
+
+    fn render_user_type_report(&self, report: &Report<UserTypeComparison>) {
+        for user_type in &report.entries {
+            match user_type {
+                UserTypeMaintained { type_name, attributes, constraints, privileges, properties } => {
+                    let has_changes = user_type.has_changes();
+
+                    if has_changes {
+                        let message = format!("  Type '{}':", type_name);
+                        println!("{}", message.color(COLOUR_CHANGED));
+                    } else if self.args.verbose {
+                        println!("  Type '{}': unchanged", type_name);
+                    }
+
+                    if has_changes || self.args.verbose {
+                        self.render_property_report(properties, 2);
+                        self.render_user_type_attribute_report(attributes);
+                        self.render_table_constraint_report(constraints);
+                        self.render_privilege_report(privileges, 2);
+                    }
+                },
+                UserTypeAdded { type_name, .. } => {
+                    let message = format!("  Type '{}': added", type_name);
+                    println!("{}", message.color(COLOUR_ADDED));
+                }
+                UserTypeRemoved { type_name, .. } => {
+                    let message = format!("  Type '{}': removed", type_name);
+                    println!("{}", message.color(COLOUR_REMOVED));
+                }
+            }
+        }
+    }
+
+    fn render_user_type_attribute_report(&self, report: &Report<UserTypeAttributeComparison>) {
+        for attribute in &report.entries {
+            match attribute {
+                AttributeMaintained { attribute_name, properties } => {
+                    let has_changes = attribute.has_changes();
+
+                    if has_changes {
+                        let message = format!("    Attribute '{}':", attribute_name);
+                        println!("{}", message.color(COLOUR_CHANGED));
+                    } else if self.args.verbose {
+                        println!("    Attribute '{}': unchanged", attribute_name);
+                    }
+
+                    if has_changes || self.args.verbose {
+                        self.render_property_report(properties, 3);
+                    }
+                },
+                AttributeAdded { attribute_name, .. } => {
+                    let message = format!("    Attribute '{}': added", attribute_name);
+                    println!("{}", message.color(COLOUR_ADDED));
+                }
+                AttributeRemoved { attribute_name } => {
+                    let message = format!("    Attribute '{}': removed", attribute_name);
+                    println!("{}", message.color(COLOUR_REMOVED));
+                }
+            }
+        }
+    }
+
+    fn render_table_policy_report(&self, report: &Report<PolicyComparison>) {
+        for policy in &report.entries {
+            match policy {
+                PolicyMaintained { policy_name, properties, .. } => {
+                    let has_changes = policy.has_changes();
+
+                    if has_changes {
+                        let message = format!("    Policy '{}':", policy_name);
+                        println!("{}", message.color(COLOUR_CHANGED).bold());
+                    } else if self.args.verbose {
+                        println!("    Policy '{}': unchanged", policy_name);
+                    }
+
+                    if has_changes || self.args.verbose {
+                        self.render_property_report(properties, 3);
+                    }
+                },
+                PolicyAdded { policy_name, .. } => {
+                    let message = format!("    Policy '{}': added", policy_name);
+                    println!("{}", message.color(COLOUR_ADDED).bold());
+                }
+                PolicyRemoved { policy_name } => {
+                    let message = format!("    Policy '{}': removed", policy_name);
+                    println!("{}", message.color(COLOUR_REMOVED).bold());
+                }
+            }
+        }
+    }
+
+    fn render_table_partition_report(&self, report: &Report<PartitionComparison>) {
+        for partition in &report.entries {
+            match partition {
+                PartitionMaintained { partition_name, properties } => {
+                    let has_changes = partition.has_changes();
 
-        differences
+                    if has_changes {
+                        let message = format!("    Partition '{}':", partition_name);
+                        println!("{}", message.color(COLOUR_CHANGED));
+                    } else if self.args.verbose {
+                        println!("    Partition '{}': unchanged", partition_name);
+                    }
+
+                    if has_changes || self.args.verbose {
+                        self.render_property_report(properties, 3);
+                    }
+                },
+                PartitionAdded { partition_name, .. } => {
+                    let message = format!("    Partition '{}': added", partition_name);
+                    println!("{}", message.color(COLOUR_ADDED));
+                }
+                PartitionRemoved { partition_name } => {
+                    let message = format!("    Partition '{}': removed", partition_name);
+                    println!("{}", message.color(COLOUR_REMOVED));
+                }
+            }
+        }
     }
 
-    fn render_view_report(&self, report: &Report<ViewComparison>) -> i32 {
-        let mut differences = 0;
+    // row-level security drift is a security concern, so it is repeated after the schema report
+    fn render_row_security_warnings(&self, report: &Report<TableComparison>) {
+        for table in &report.entries {
+            if let TableMaintained { table_name, policies, properties, .. } = table {
+                let flags_changed = properties.entries.iter().any(|p| matches!(p, PropertyChanged { property_name, .. } if property_name == "row_security" || property_name == "force_row_security"));
 
+                if flags_changed || policies.has_changes() {
+                    let message = format!("  Warning: row-level security differs on table '{}'", table_name);
+                    println!("{}", message.color(COLOUR_SECURITY).bold());
+                }
+            }
+        }
+    }
+
+    fn render_view_report(&self, report: &Report<ViewComparison>) {
         for view in &report.entries {
             match view {
                 ViewMaintained { view_name, properties } => {
@@ -454,12 +690,57 @@ impl CLI {
                     }
                     
                     if has_changes || self.args.verbose {
-                        differences += self.render_property_report(&properties, 2);
+                        self.render_property_report(properties, 2);
+                    }
+                },
+                ViewAdded { view_name, .. } => {
+                    let message = format!("  View '{}': added", view_name);
+                    println!("{}", message.color(COLOUR_ADDED));
+                }
+                ViewRemoved { view_name } => {
+                    let message = format!("  View '{}': removed", view_name);
+                    println!("{}", message.color(COLOUR_REMOVED));
+                }
+            }
+        }
+    }
+
+    fn render_materialized_view_report(&self, report: &Report<MaterializedViewComparison>) {
+        for view in &report.entries {
+            match view {
+                MaterializedViewMaintained { view_name, indices, privileges, properties, .. } => {
+                    let has_changes = view.has_changes();
+
+                    if has_changes {
+                        let message = format!("  Materialized view '{}':", view_name);
+                        println!("{}", message.color(COLOUR_CHANGED));
+                    } else if self.args.verbose {
+                        println!("  Materialized view '{}': unchanged", view_name);
+                    }
+
+                    if has_changes || self.args.verbose {
+                        self.render_property_report(properties, 2);
+                        self.render_privilege_report(privileges, 2);
+                        self.render_table_index_report(indices);
                     }
                 },
+                MaterializedViewAdded { view_name, .. } => {
+                    let message = format!("  Materialized view '{}': added", view_name);
+                    println!("{}", message.color(COLOUR_ADDED));
+                }
+                MaterializedViewRemoved { view_name } => {
+                    let message = format!("  Materialized view '{}': removed", view_name);
+                    println!("{}", message.color(COLOUR_REMOVED));
+                }
             }
         }
+    }
+}
 
-        differences
+async fn open_database(location: &str) -> Result<Box<dyn SchemaSource>, Failure> {
+    if location.starts_with("postgres://") || location.starts_with("postgresql://") {
+        Ok(Box::new(db::Database::connect(location).await.map_err(Failure::connection)?))
+    } else {
+        Ok(Box::new(Snapshot::read(Path::new(location)).map_err(Failure::other)?.catalog))
     }
 }
@@ -0,0 +1,233 @@
+use crate::db::column::Column;
+use crate::db::extension::Extension;
+use crate::db::index::Index;
+use crate::db::materialized_view::MaterializedView;
+use crate::db::sequence::Sequence;
+use crate::db::table::Table;
+use crate::db::table_constraint::TableConstraint;
+use crate::db::table_policy::TablePolicy;
+use crate::db::table_trigger::TableTrigger;
+use crate::db::user_type::UserType;
+use crate::db::user_type_attribute::UserTypeAttribute;
+use crate::db::user_type_constraint::UserTypeConstraint;
+use crate::db::view::View;
+use crate::string::{qualified_name, quote_identifier, quote_literal};
+
+pub fn column_definition(column: &Column) -> String {
+    let mut definition = format!("{} {}", quote_identifier(&column.column_name), column.column_type);
+
+    if column.is_identity == "YES" {
+        definition.push_str(&format!(" GENERATED {} AS IDENTITY", column.identity_generation.as_deref().unwrap_or("BY DEFAULT")));
+    } else if column.is_generated == "ALWAYS" {
+        definition.push_str(&format!(" GENERATED ALWAYS AS ({}) STORED", column.generation_expression.as_deref().unwrap_or_default()));
+    } else if let Some(column_default) = &column.column_default {
+        definition.push_str(&format!(" DEFAULT {}", column_default));
+    }
+
+    if column.is_nullable == "NO" {
+        definition.push_str(" NOT NULL");
+    }
+
+    definition
+}
+
+pub fn extension_definition(extension: &Extension) -> String {
+    format!("CREATE EXTENSION {} WITH SCHEMA {} VERSION {}",
+            quote_identifier(&extension.extension_name),
+            quote_identifier(&extension.extension_schema),
+            quote_literal(&extension.extension_version))
+}
+
+pub fn table_definition(table: &Table,
+                        columns: &[&Column],
+                        constraints: &[&TableConstraint],
+                        indices: &[&Index],
+                        triggers: &[&TableTrigger],
+                        policies: &[&TablePolicy],
+) -> String {
+    let name = qualified_name(&table.table_schema, &table.table_name);
+
+    // partitions take their columns, constraints, indices and triggers from the parent
+    if table.partition_parent_name.is_some() {
+        let mut statements = vec![partition_definition(table)];
+        statements.extend(row_security_definitions(table, policies));
+
+        return statements.join(";\n")
+    }
+
+    // inherited columns are created by INHERITS
+    let mut columns: Vec<&Column> = columns.iter().filter(|c| table.parents.is_none() || c.is_local == "YES").cloned().collect();
+    columns.sort_by_key(|c| c.ordinal_position);
+
+    let mut elements: Vec<String> = columns.iter().map(|c| column_definition(c)).collect();
+
+    for constraint in constraints {
+        if let Some(definition) = &constraint.definition {
+            elements.push(format!("CONSTRAINT {} {}", quote_identifier(&constraint.constraint_name), definition));
+        }
+    }
+
+    let mut definition = format!("CREATE TABLE {} (\n    {}\n)", name, elements.join(",\n    "));
+
+    if let Some(parents) = &table.parents {
+        definition.push_str(&format!(" INHERITS ({})", parents));
+    }
+
+    if let (Some(partition_strategy), Some(partition_key)) = (&table.partition_strategy, &table.partition_key) {
+        definition.push_str(&format!(" PARTITION BY {} {}", partition_strategy, partition_key));
+    }
+
+    let mut statements = vec![definition];
+
+    for index in indices {
+        if !constraints.iter().any(|c| c.constraint_name == index.index_name) {
+            statements.push(index.definition.clone());
+        }
+    }
+
+    let mut trigger_names: Vec<&str> = Vec::new();
+
+    for trigger in triggers {
+        if let Some(definition) = &trigger.definition {
+            if !trigger_names.contains(&trigger.trigger_name.as_str()) {
+                trigger_names.push(&trigger.trigger_name);
+                statements.push(definition.clone());
+            }
+        }
+    }
+
+    statements.extend(row_security_definitions(table, policies));
+
+    statements.join(";\n")
+}
+
+pub fn partition_definition(table: &Table) -> String {
+    let mut definition = format!("CREATE TABLE {} PARTITION OF {} {}",
+                                 qualified_name(&table.table_schema, &table.table_name),
+                                 qualified_name(table.partition_parent_schema.as_deref().unwrap_or(&table.table_schema), table.partition_parent_name.as_deref().unwrap_or_default()),
+                                 table.partition_bound.as_deref().unwrap_or("DEFAULT"));
+
+    if let (Some(partition_strategy), Some(partition_key)) = (&table.partition_strategy, &table.partition_key) {
+        definition.push_str(&format!(" PARTITION BY {} {}", partition_strategy, partition_key));
+    }
+
+    definition
+}
+
+fn row_security_definitions(table: &Table, policies: &[&TablePolicy]) -> Vec<String> {
+    let name = qualified_name(&table.table_schema, &table.table_name);
+    let mut statements = Vec::new();
+
+    if table.row_security == "YES" {
+        statements.push(format!("ALTER TABLE {} ENABLE ROW LEVEL SECURITY", name));
+    }
+
+    if table.force_row_security == "YES" {
+        statements.push(format!("ALTER TABLE {} FORCE ROW LEVEL SECURITY", name));
+    }
+
+    statements.extend(policies.iter().map(|p| policy_definition(p)));
+
+    statements
+}
+
+pub fn policy_definition(policy: &TablePolicy) -> String {
+    let roles: Vec<String> = policy.roles.split(", ").map(|r| if r == "public" { String::from(r) } else { quote_identifier(r) }).collect();
+
+    let mut definition = format!("CREATE POLICY {} ON {} AS {} FOR {} TO {}",
+                                 quote_identifier(&policy.policy_name),
+                                 qualified_name(&policy.table_schema, &policy.table_name),
+                                 policy.permissive,
+                                 policy.command,
+                                 roles.join(", "));
+
+    if let Some(using_expression) = &policy.using_expression {
+        definition.push_str(&format!(" USING ({})", using_expression));
+    }
+
+    if let Some(check_expression) = &policy.check_expression {
+        definition.push_str(&format!(" WITH CHECK ({})", check_expression));
+    }
+
+    definition
+}
+
+pub fn view_definition(view: &View) -> String {
+    format!("CREATE VIEW {} AS{}",
+            qualified_name(&view.table_schema, &view.table_name),
+            view.view_definition.as_deref().unwrap_or_default().trim_end().trim_end_matches(';'))
+}
+
+pub fn materialized_view_definition(view: &MaterializedView, indices: &[&Index]) -> String {
+    let mut definition = format!("CREATE MATERIALIZED VIEW {}", qualified_name(&view.view_schema, &view.view_name));
+
+    if let Some(table_space) = &view.table_space {
+        definition.push_str(&format!(" TABLESPACE {}", quote_identifier(table_space)));
+    }
+
+    definition.push_str(&format!(" AS{}", view.view_definition.as_deref().unwrap_or_default().trim_end().trim_end_matches(';')));
+
+    if view.is_populated == "NO" {
+        definition.push_str(" WITH NO DATA");
+    }
+
+    let mut statements = vec![definition];
+    statements.extend(indices.iter().map(|i| i.definition.clone()));
+
+    statements.join(";\n")
+}
+
+pub fn sequence_definition(sequence: &Sequence) -> String {
+    format!("CREATE SEQUENCE {} AS {} INCREMENT BY {} MINVALUE {} MAXVALUE {} START WITH {} {}",
+            qualified_name(&sequence.sequence_schema, &sequence.sequence_name),
+            sequence.data_type,
+            sequence.increment,
+            sequence.minimum_value,
+            sequence.maximum_value,
+            sequence.start_value,
+            if sequence.cycle_option == "YES" { "CYCLE" } else { "NO CYCLE" })
+}
+
+pub fn user_type_attribute_definition(attribute: &UserTypeAttribute) -> String {
+    let mut definition = format!("{} {}", quote_identifier(&attribute.attribute_name), attribute.data_type);
+
+    if let Some(collation_name) = attribute.collation_name.as_deref().filter(|c| *c != "default") {
+        definition.push_str(&format!(" COLLATE {}", quote_identifier(collation_name)));
+    }
+
+    definition
+}
+
+pub fn user_type_definition(user_type: &UserType, attributes: &[&UserTypeAttribute], constraints: &[&UserTypeConstraint]) -> String {
+    let name = qualified_name(&user_type.type_schema, &user_type.type_name);
+
+    match user_type.type_kind.as_str() {
+        "COMPOSITE" => {
+            let mut attributes = attributes.to_vec();
+            attributes.sort_by_key(|a| a.ordinal_position);
+
+            let elements: Vec<String> = attributes.iter().map(|a| user_type_attribute_definition(a)).collect();
+
+            format!("CREATE TYPE {} AS (\n    {}\n)", name, elements.join(",\n    "))
+        },
+        "DOMAIN" => {
+            let mut definition = format!("CREATE DOMAIN {} AS {}", name, user_type.base_type.as_deref().unwrap_or_default());
+
+            if let Some(default_value) = &user_type.default_value {
+                definition.push_str(&format!(" DEFAULT {}", default_value));
+            }
+
+            if user_type.is_not_null == "YES" {
+                definition.push_str(" NOT NULL");
+            }
+
+            for constraint in constraints.iter().filter(|c| c.definition != "NOT NULL") {
+                definition.push_str(&format!(" CONSTRAINT {} {}", quote_identifier(&constraint.constraint_name), constraint.definition));
+            }
+
+            definition
+        },
+        "RANGE" => format!("CREATE TYPE {} AS RANGE (SUBTYPE = {})", name, user_type.range_subtype.as_deref().unwrap_or_default()),
+        _ => format!("CREATE TYPE {} AS ENUM ({})", name, user_type.enum_labels.as_deref().unwrap_or_default()),
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+use crate::db::catalog::Catalog;
+use crate::db::table::Table;
+use crate::string::matches_glob;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectKind {
+    Column,
+    Constraint,
+    Extension,
+    Index,
+    MaterializedView,
+    Policy,
+    Routine,
+    Sequence,
+    Table,
+    Trigger,
+    Type,
+    View,
+}
+
+const OBJECT_KINDS: &[(&str, ObjectKind)] = &[
+    ("column", ObjectKind::Column),
+    ("constraint", ObjectKind::Constraint),
+    ("extension", ObjectKind::Extension),
+    ("index", ObjectKind::Index),
+    ("materialized_view", ObjectKind::MaterializedView),
+    ("policy", ObjectKind::Policy),
+    ("routine", ObjectKind::Routine),
+    ("sequence", ObjectKind::Sequence),
+    ("table", ObjectKind::Table),
+    ("trigger", ObjectKind::Trigger),
+    ("type", ObjectKind::Type),
+    ("view", ObjectKind::View),
+];
+
+// the properties compared for each kind of object, by the object path they are reported under
+const PROPERTY_PATHS: &[(&str, &[&str])] = &[
+    ("extension", &["extension_version", "extension_schema", "is_relocatable"]),
+    ("schema", &["schema_owner", "default_character_set_catalog", "default_character_set_schema", "default_character_set_name"]),
+    ("materialized_view", &["view_owner", "table_space", "view_definition", "is_populated"]),
+    ("materialized_view.index", &["definition"]),
+    ("routine", &[
+        "routine_type", "module_catalog", "module_schema", "module_name", "udt_catalog", "udt_schema", "udt_name",
+        "data_type", "character_maximum_length", "character_octet_length", "character_set_catalog",
+        "character_set_schema", "character_set_name", "collation_catalog", "collation_schema", "collation_name",
+        "numeric_precision", "numeric_precision_radix", "numeric_scale", "datetime_precision", "interval_type",
+        "interval_precision", "type_udt_catalog", "type_udt_schema", "type_udt_name", "maximum_cardinality",
+        "dtd_identifier", "routine_body", "definition", "routine_definition", "external_name", "external_language",
+        "parameter_style", "is_deterministic", "sql_data_access", "is_null_call", "sql_path", "schema_level_routine",
+        "max_dynamic_result_sets", "is_user_defined_cast", "is_implicitly_invocable", "security_type", "volatility",
+        "is_strict", "parallel_safety", "estimated_cost", "estimated_rows", "is_leakproof", "is_security_definer",
+        "configuration",
+    ]),
+    ("sequence", &[
+        "sequence_catalog", "sequence_schema", "sequence_name", "data_type", "numeric_precision",
+        "numeric_precision_radix", "numeric_scale", "start_value", "minimum_value", "maximum_value", "increment",
+        "cycle_option",
+    ]),
+    ("table", &[
+        "table_catalog", "table_schema", "table_name", "table_type", "self_referencing_column_name",
+        "reference_generation", "user_defined_type_catalog", "user_defined_type_schema", "user_defined_type_name",
+        "is_insertable_into", "is_typed", "commit_action", "row_security", "force_row_security", "partition_strategy",
+        "partition_key", "partition_parent_schema", "partition_parent_name", "partition_bound", "parents",
+    ]),
+    ("table.column", &[
+        "column_default", "is_nullable", "data_type", "column_type", "character_maximum_length",
+        "character_octet_length", "numeric_precision", "numeric_precision_radix", "numeric_scale",
+        "datetime_precision", "interval_type", "interval_precision", "character_set_catalog", "character_set_schema",
+        "character_set_name", "collation_catalog", "collation_schema", "collation_name", "domain_catalog",
+        "domain_schema", "domain_name", "udt_catalog", "udt_schema", "udt_name", "scope_catalog", "scope_schema",
+        "scope_name", "maximum_cardinality", "dtd_identifier", "is_self_referencing", "is_identity",
+        "identity_generation", "identity_start", "identity_increment", "identity_maximum", "identity_minimum",
+        "identity_cycle", "is_generated", "generation_expression", "is_updatable", "ordinal_position", "is_local",
+        "inheritance_count",
+    ]),
+    ("table.constraint", &[
+        "constraint_catalog", "constraint_schema", "table_name", "constraint_type", "is_deferrable",
+        "initially_deferred", "enforced", "nulls_distinct", "definition", "key_columns", "referenced_table_schema",
+        "referenced_table_name", "referenced_columns", "match_option", "update_rule", "delete_rule",
+    ]),
+    ("table.index", &["definition"]),
+    ("table.partition", &["partition_parent_name", "partition_bound", "partition_strategy", "partition_key"]),
+    ("table.policy", &["command", "permissive", "roles", "using_expression", "check_expression"]),
+    ("table.trigger", &[
+        "trigger_catalog", "trigger_schema", "action_order", "action_condition", "action_statement",
+        "action_orientation", "action_timing", "action_reference_old_table", "action_reference_new_table",
+    ]),
+    ("type", &["type_kind", "type_owner", "enum_labels", "base_type", "default_value", "is_not_null", "range_subtype"]),
+    ("type.attribute", &["data_type", "collation_name", "ordinal_position"]),
+    ("type.constraint", &["definition", "is_validated"]),
+    ("view", &[
+        "view_definition", "check_option", "is_updatable", "is_insertable_into", "is_trigger_updatable",
+        "is_trigger_deletable", "is_trigger_insertable_into",
+    ]),
+];
+
+/// Matches an ignored property pattern, such as `table.column.*_type`, against the path of a property: its object
+/// path followed by its name. Owner properties, such as `schema_owner`, can also be matched as `<object path>.owner`.
+pub fn matches_property_path(pattern: &str, object_path: &str, property_name: &str) -> bool {
+    matches_glob(pattern, &format!("{}.{}", object_path, property_name)) ||
+        (property_name.ends_with("_owner") && matches_glob(pattern, &format!("{}.owner", object_path)))
+}
+
+/// Whether an ignored property pattern matches the path of any property that is compared.
+pub fn is_known_property_pattern(pattern: &str) -> bool {
+    PROPERTY_PATHS.iter()
+        .any(|(object_path, property_names)| property_names.iter().any(|name| matches_property_path(pattern, object_path, name)))
+}
+
+/// A glob pattern, such as `table:audit_*`, matched against the names of objects of one kind.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectFilter {
+    pub kind: ObjectKind,
+    pub pattern: String,
+}
+
+impl ObjectFilter {
+    pub fn matches(&self, kind: ObjectKind, name: &str) -> bool {
+        self.kind == kind && matches_glob(&self.pattern, name)
+    }
+}
+
+impl FromStr for ObjectFilter {
+    type Err = String;
+
+    fn from_str(filter: &str) -> Result<Self, Self::Err> {
+        let Some((kind_name, pattern)) = filter.split_once(':') else {
+            return Err(format!("'{}' is not of the form KIND:PATTERN", filter));
+        };
+
+        match OBJECT_KINDS.iter().find(|(name, _)| *name == kind_name) {
+            Some((_, kind)) => Ok(ObjectFilter { kind: *kind, pattern: String::from(pattern) }),
+            None => {
+                let kind_names: Vec<&str> = OBJECT_KINDS.iter().map(|(name, _)| *name).collect();
+                Err(format!("unknown object kind '{}', expected one of: {}", kind_name, kind_names.join(", ")))
+            },
+        }
+    }
+}
+
+/// Removes the objects not selected by the filters: where there are include filters for a kind, objects of that kind
+/// must match one of them, and objects matching any exclude filter are removed. Partitions are selected with the
+/// table they belong to.
+pub fn filter_objects(catalog: &mut Catalog, include: &[ObjectFilter], exclude: &[ObjectFilter]) {
+    let is_selected = |kind: ObjectKind, name: &str| {
+        let mut includes = include.iter().filter(|f| f.kind == kind).peekable();
+        let is_included = includes.peek().is_none() || includes.any(|f| f.matches(kind, name));
+
+        is_included && !exclude.iter().any(|f| f.matches(kind, name))
+    };
+
+    catalog.columns.retain(|c| is_selected(ObjectKind::Column, &c.column_name));
+    catalog.extensions.retain(|e| is_selected(ObjectKind::Extension, &e.extension_name));
+    catalog.indices.retain(|i| is_selected(ObjectKind::Index, &i.index_name));
+    catalog.materialized_views.retain(|v| is_selected(ObjectKind::MaterializedView, &v.view_name));
+    catalog.routines.retain(|r| is_selected(ObjectKind::Routine, &r.routine_name));
+    catalog.sequences.retain(|s| is_selected(ObjectKind::Sequence, &s.sequence_name));
+    let selected_tables: HashSet<(String, String)> = catalog.tables.iter()
+        .filter(|t| {
+            let root = partition_root(&catalog.tables, t);
+            let kind = if root.table_type == "VIEW" { ObjectKind::View } else { ObjectKind::Table };
+            is_selected(kind, &root.table_name)
+        })
+        .map(|t| (t.table_schema.clone(), t.table_name.clone()))
+        .collect();
+    catalog.tables.retain(|t| selected_tables.contains(&(t.table_schema.clone(), t.table_name.clone())));
+    catalog.table_constraints.retain(|c| is_selected(ObjectKind::Constraint, &c.constraint_name));
+    catalog.table_policies.retain(|p| is_selected(ObjectKind::Policy, &p.policy_name));
+    catalog.table_triggers.retain(|t| is_selected(ObjectKind::Trigger, &t.trigger_name));
+    catalog.user_types.retain(|t| is_selected(ObjectKind::Type, &t.type_name));
+    catalog.user_type_constraints.retain(|c| is_selected(ObjectKind::Constraint, &c.constraint_name));
+    catalog.views.retain(|v| is_selected(ObjectKind::View, &v.table_name));
+}
+
+// the table a partition, or a partition of a partition, ultimately belongs to; the table itself if it is not a partition
+fn partition_root<'a>(tables: &'a [Table], table: &'a Table) -> &'a Table {
+    let parent = tables.iter().find(|t| {
+        table.partition_parent_schema.as_ref() == Some(&t.table_schema) && table.partition_parent_name.as_ref() == Some(&t.table_name)
+    });
+
+    parent.map_or(table, |parent| partition_root(tables, parent))
+}
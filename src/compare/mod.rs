@@ -1,15 +1,24 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use itertools::Itertools;
 use sqlx::Error;
 
+use crate::compare::filter::{filter_objects, matches_property_path, ObjectFilter};
+use crate::compare::report::database::DatabaseComparison;
+use crate::compare::report::extension::ExtensionComparison;
+use crate::compare::report::extension::ExtensionComparison::{ExtensionAdded, ExtensionMaintained, ExtensionRemoved};
 use crate::compare::report::index::IndexComparison;
+use crate::compare::report::policy::PolicyComparison;
+use crate::compare::report::policy::PolicyComparison::{PolicyAdded, PolicyMaintained, PolicyRemoved};
+use crate::compare::report::materialized_view::MaterializedViewComparison;
+use crate::compare::report::partition::PartitionComparison;
+use crate::compare::report::partition::PartitionComparison::{PartitionAdded, PartitionMaintained, PartitionRemoved};
+use crate::compare::report::materialized_view::MaterializedViewComparison::{MaterializedViewAdded, MaterializedViewMaintained, MaterializedViewRemoved};
 use crate::compare::report::privilege::PrivilegeComparison;
 use crate::compare::report::privilege::PrivilegeComparison::{PrivilegeAdded, PrivilegeMaintained, PrivilegeRemoved};
 use crate::compare::report::property::PropertyComparison;
 use crate::compare::report::property::PropertyComparison::{PropertyChanged, PropertyUnchanged};
 use crate::compare::report::Report;
-use crate::compare::report::schema::SchemaComparison;
 use crate::compare::report::schema::SchemaComparison::{SchemaAdded, SchemaMaintained, SchemaMissing, SchemaRemoved};
 use crate::compare::report::sequence::SequenceComparison;
 use crate::compare::report::sequence::SequenceComparison::{SequenceAdded, SequenceMaintained, SequenceRemoved};
@@ -24,89 +33,166 @@ use crate::compare::report::table_constraint::TableConstraintComparison;
 use crate::compare::report::table_constraint::TableConstraintComparison::{ConstraintAdded, ConstraintMaintained, ConstraintRemoved};
 use crate::compare::report::table_trigger::TableTriggerComparison;
 use crate::compare::report::table_trigger::TableTriggerComparison::{TriggerAdded, TriggerMaintained, TriggerRemoved};
+use crate::compare::report::user_type::UserTypeComparison;
+use crate::compare::report::user_type::UserTypeComparison::{UserTypeAdded, UserTypeMaintained, UserTypeRemoved};
+use crate::compare::report::user_type_attribute::UserTypeAttributeComparison;
+use crate::compare::report::user_type_attribute::UserTypeAttributeComparison::{AttributeAdded, AttributeMaintained, AttributeRemoved};
 use crate::compare::report::view::ViewComparison;
-use crate::compare::report::view::ViewComparison::ViewMaintained;
-use crate::db::Database;
+use crate::compare::report::view::ViewComparison::{ViewAdded, ViewMaintained, ViewRemoved};
+use crate::db::catalog::Catalog;
+use crate::db::source::SchemaSource;
 use crate::db::schema::Schema;
 use crate::db::table::Table;
 use crate::db::column::Column;
 use crate::db::column_privilege::ColumnPrivilege;
+use crate::db::extension::Extension;
 use crate::db::index::Index;
+use crate::db::materialized_view::MaterializedView;
+use crate::db::materialized_view_privilege::MaterializedViewPrivilege;
 use crate::db::privilege::Privilege;
 use crate::db::routine::Routine;
 use crate::db::routine_parameters::RoutineParameter;
 use crate::db::routine_privilege::RoutinePrivilege;
 use crate::db::table_constraint::TableConstraint;
+use crate::db::table_policy::TablePolicy;
 use crate::db::table_privilege::TablePrivilege;
 use crate::db::table_trigger::TableTrigger;
 use crate::db::sequence::Sequence;
+use crate::db::user_type::UserType;
+use crate::db::user_type_attribute::UserTypeAttribute;
+use crate::db::user_type_constraint::UserTypeConstraint;
+use crate::db::user_type_privilege::UserTypePrivilege;
 use crate::db::view::View;
-use crate::string::EqualIgnoreWhitespace;
+use crate::compare::definition::{column_definition, extension_definition, materialized_view_definition, partition_definition, policy_definition, sequence_definition, table_definition, user_type_attribute_definition, user_type_definition, view_definition};
+use crate::string::{normalize_sql, unified_diff, EqualIgnoreWhitespace};
 
+mod definition;
+pub mod filter;
+#[cfg(test)]
+mod tests;
 pub mod report;
 
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub ignore_whitespace: bool,
+    pub ignore_column_ordinal: bool,
+    pub ignore_privileges: bool,
+    pub collapse_partitions: bool,
+    pub distinguish_inherited_columns: bool,
+    pub ignore_extension_objects: bool,
+    pub compare_routine_definitions: bool,
+    pub normalize_sql: bool,
+    pub include: Vec<ObjectFilter>,
+    pub exclude: Vec<ObjectFilter>,
+    pub ignore_properties: Vec<String>,
+    // right-hand schemas to compare in place of the left-hand schema of the same name as the key
+    pub schema_map: HashMap<String, String>,
+}
+
 pub struct Comparer {
-    left_db: Database,
-    right_db: Database,
-    ignore_whitespace: bool,
-    ignore_column_ordinal: bool,
-    ignore_privileges: bool,
+    left_db: Box<dyn SchemaSource>,
+    right_db: Box<dyn SchemaSource>,
+    options: Options,
 }
 
 impl Comparer {
-    pub fn new(left_db: Database, right_db: Database, ignore_whitespace: bool, ignore_column_ordinal: bool, ignore_privileges: bool) -> Comparer {
+    pub fn new(left_db: Box<dyn SchemaSource>, right_db: Box<dyn SchemaSource>, options: Options) -> Comparer {
         Comparer {
             left_db,
             right_db,
-            ignore_whitespace,
-            ignore_column_ordinal,
-            ignore_privileges,
+            options,
         }
     }
 
-    pub async fn compare(&mut self, schemas: Vec<String>) -> Result<Report<SchemaComparison>, Error> {
+    pub async fn compare(&self, schemas: Vec<String>) -> Result<DatabaseComparison, Error> {
         let mut entries = Vec::new();
 
-        let left_columns = self.left_db.columns(&schemas[..]).await?;
-        let right_columns = self.right_db.columns(&schemas[..]).await?;
-        let left_column_privileges = self.left_db.column_privileges(&schemas[..]).await?;
-        let right_column_privileges = self.right_db.column_privileges(&schemas[..]).await?;
-        let left_routines = self.left_db.routines(&schemas[..]).await?;
-        let right_routines = self.right_db.routines(&schemas[..]).await?;
-        let left_routine_parameters = self.left_db.routine_parameters(&schemas[..]).await?;
-        let right_routine_parameters = self.right_db.routine_parameters(&schemas[..]).await?;
-        let left_routine_privileges = self.left_db.routine_privileges(&schemas[..]).await?;
-        let right_routine_privileges = self.right_db.routine_privileges(&schemas[..]).await?;
-        let left_schemas = self.left_db.schemas(&schemas[..]).await?;
-        let right_schemas = self.right_db.schemas(&schemas[..]).await?;
-        let left_sequences = self.left_db.sequences(&schemas[..]).await?;
-        let right_sequences = self.right_db.sequences(&schemas[..]).await?;
-        let left_tables = self.left_db.tables(&schemas[..]).await?;
-        let right_tables = self.right_db.tables(&schemas[..]).await?;
-        let left_table_constraints = self.left_db.table_constraints(&schemas[..]).await?;
-        let right_table_constraints = self.right_db.table_constraints(&schemas[..]).await?;
-        let left_indices = self.left_db.indices(&schemas[..]).await?;
-        let right_indices = self.right_db.indices(&schemas[..]).await?;
-        let left_table_privileges = self.left_db.table_privileges(&schemas[..]).await?;
-        let right_table_privileges = self.right_db.table_privileges(&schemas[..]).await?;
-        let left_table_triggers = self.left_db.table_triggers(&schemas[..]).await?;
-        let right_table_triggers = self.right_db.table_triggers(&schemas[..]).await?;
-        let left_views = self.left_db.views(&schemas[..]).await?;
-        let right_views = self.right_db.views(&schemas[..]).await?;
-        
+        let right_schemas: Vec<String> = schemas.iter()
+            .map(|s| self.options.schema_map.get(s).unwrap_or(s).clone())
+            .collect();
+
+        let (mut left, mut right) = futures::try_join!(
+            Catalog::capture(self.left_db.as_ref(), &schemas[..]),
+            Catalog::capture(self.right_db.as_ref(), &right_schemas[..]))?;
+
+        if !self.options.schema_map.is_empty() {
+            right = self.map_schemas(right, &schemas).await?;
+        }
+
+        if self.options.ignore_extension_objects {
+            remove_extension_members(&mut left);
+            remove_extension_members(&mut right);
+        }
+
+        filter_objects(&mut left, &self.options.include, &self.options.exclude);
+        filter_objects(&mut right, &self.options.include, &self.options.exclude);
+
+        let extensions = self.compare_extensions(&left.extensions, &right.extensions)?;
+
+        let Catalog {
+            columns: left_columns,
+            materialized_views: left_materialized_views,
+            materialized_view_privileges: left_materialized_view_privileges,
+            column_privileges: left_column_privileges,
+            routines: left_routines,
+            routine_parameters: left_routine_parameters,
+            routine_privileges: left_routine_privileges,
+            schemas: left_schemas,
+            sequences: left_sequences,
+            tables: left_tables,
+            table_constraints: left_table_constraints,
+            table_policies: left_table_policies,
+            indices: left_indices,
+            table_privileges: left_table_privileges,
+            table_triggers: left_table_triggers,
+            user_types: left_user_types,
+            user_type_attributes: left_user_type_attributes,
+            user_type_constraints: left_user_type_constraints,
+            user_type_privileges: left_user_type_privileges,
+            views: left_views,
+            ..
+        } = left;
+
+        let Catalog {
+            columns: right_columns,
+            materialized_views: right_materialized_views,
+            materialized_view_privileges: right_materialized_view_privileges,
+            column_privileges: right_column_privileges,
+            routines: right_routines,
+            routine_parameters: right_routine_parameters,
+            routine_privileges: right_routine_privileges,
+            schemas: right_schemas,
+            sequences: right_sequences,
+            tables: right_tables,
+            table_constraints: right_table_constraints,
+            table_policies: right_table_policies,
+            indices: right_indices,
+            table_privileges: right_table_privileges,
+            table_triggers: right_table_triggers,
+            user_types: right_user_types,
+            user_type_attributes: right_user_type_attributes,
+            user_type_constraints: right_user_type_constraints,
+            user_type_privileges: right_user_type_privileges,
+            views: right_views,
+            ..
+        } = right;
+
         for schema in schemas {
             let left_schema = left_schemas.iter().filter(|s| s.schema_name == schema).at_most_one().map_err(|_| Error::RowNotFound )?;
             let right_schema = right_schemas.iter().filter(|s| s.schema_name == schema).at_most_one().map_err(|_| Error::RowNotFound)?;
 
-            if left_schema.is_none() && right_schema.is_none() {
-                entries.push(SchemaMissing { schema_name: String::from(schema) });
-            } else {
-                if left_schema.is_none() {
-                    entries.push(SchemaAdded { schema_name: String::from(schema) });
-                } else if right_schema.is_none() {
-                    entries.push(SchemaRemoved { schema_name: String::from(schema) });
-                } else {
-                    let properties = self.compare_schema_properties(&left_schema.unwrap(), &right_schema.unwrap());
+            match (left_schema, right_schema) {
+                (None, None) => {
+                    entries.push(SchemaMissing { schema_name: schema });
+                },
+                (None, Some(_)) => {
+                    entries.push(SchemaAdded { schema_name: schema });
+                },
+                (Some(_), None) => {
+                    entries.push(SchemaRemoved { schema_name: schema });
+                },
+                (Some(left_schema), Some(right_schema)) => {
+                    let properties = self.ignore_properties("schema", self.compare_schema_properties(left_schema, right_schema));
 
                     let left_schema_routines = left_routines.iter().filter(|r| r.routine_schema == schema).collect();
                     let right_schema_routines = right_routines.iter().filter(|r| r.routine_schema == schema).collect();
@@ -122,6 +208,20 @@ impl Comparer {
                         left_schema_routine_privileges,
                         right_schema_routine_privileges)?;
 
+                    let left_schema_materialized_views = left_materialized_views.iter().filter(|v| v.view_schema == schema).collect();
+                    let right_schema_materialized_views = right_materialized_views.iter().filter(|v| v.view_schema == schema).collect();
+                    let left_schema_materialized_view_indices = left_indices.iter().filter(|i| i.table_schema == schema).collect();
+                    let right_schema_materialized_view_indices = right_indices.iter().filter(|i| i.table_schema == schema).collect();
+                    let left_schema_materialized_view_privileges = left_materialized_view_privileges.iter().filter(|p| p.view_schema == schema).collect();
+                    let right_schema_materialized_view_privileges = right_materialized_view_privileges.iter().filter(|p| p.view_schema == schema).collect();
+                    let materialized_views = self.compare_materialized_views(
+                        left_schema_materialized_views,
+                        right_schema_materialized_views,
+                        left_schema_materialized_view_indices,
+                        right_schema_materialized_view_indices,
+                        left_schema_materialized_view_privileges,
+                        right_schema_materialized_view_privileges)?;
+
                     let left_schema_sequences = left_sequences.iter().filter(|s| s.sequence_schema == schema).collect();
                     let right_schema_sequences = right_sequences.iter().filter(|s| s.sequence_schema == schema).collect();
                     let sequences = self.compare_sequences(left_schema_sequences, right_schema_sequences)?;
@@ -138,8 +238,12 @@ impl Comparer {
                     let right_schema_table_constraints = right_table_constraints.iter().filter(|c| c.table_schema == schema).collect();
                     let left_schema_table_triggers = left_table_triggers.iter().filter(|t| t.event_object_schema == schema).collect();
                     let right_schema_table_triggers = right_table_triggers.iter().filter(|t| t.event_object_schema == schema).collect();
+                    let left_schema_table_policies = left_table_policies.iter().filter(|p| p.table_schema == schema).collect();
+                    let right_schema_table_policies = right_table_policies.iter().filter(|p| p.table_schema == schema).collect();
                     let left_schema_column_privileges = left_column_privileges.iter().filter(|p| p.table_schema == schema).collect();
                     let right_schema_column_privileges = right_column_privileges.iter().filter(|p| p.table_schema == schema).collect();
+                    let left_schema_views: Vec<&View> = left_views.iter().filter(|t| t.table_schema == schema).collect();
+                    let right_schema_views: Vec<&View> = right_views.iter().filter(|t| t.table_schema == schema).collect();
                     let tables = self.compare_tables(
                         left_schema_tables,
                         right_schema_tables,
@@ -155,20 +259,82 @@ impl Comparer {
                         right_schema_table_constraints,
                         left_schema_table_triggers,
                         right_schema_table_triggers,
+                        left_schema_table_policies,
+                        right_schema_table_policies,
                     )?;
 
-                    let left_schema_views = left_views.iter().filter(|t| t.table_schema == schema).collect();
-                    let right_schema_views = right_views.iter().filter(|t| t.table_schema == schema).collect();
+                    let left_schema_user_types = left_user_types.iter().filter(|t| t.type_schema == schema).collect();
+                    let right_schema_user_types = right_user_types.iter().filter(|t| t.type_schema == schema).collect();
+                    let left_schema_user_type_attributes = left_user_type_attributes.iter().filter(|a| a.type_schema == schema).collect();
+                    let right_schema_user_type_attributes = right_user_type_attributes.iter().filter(|a| a.type_schema == schema).collect();
+                    let left_schema_user_type_constraints = left_user_type_constraints.iter().filter(|c| c.type_schema == schema).collect();
+                    let right_schema_user_type_constraints = right_user_type_constraints.iter().filter(|c| c.type_schema == schema).collect();
+                    let left_schema_user_type_privileges = left_user_type_privileges.iter().filter(|p| p.type_schema == schema).collect();
+                    let right_schema_user_type_privileges = right_user_type_privileges.iter().filter(|p| p.type_schema == schema).collect();
+                    let types = self.compare_user_types(
+                        left_schema_user_types,
+                        right_schema_user_types,
+                        left_schema_user_type_attributes,
+                        right_schema_user_type_attributes,
+                        left_schema_user_type_constraints,
+                        right_schema_user_type_constraints,
+                        left_schema_user_type_privileges,
+                        right_schema_user_type_privileges)?;
+
                     let views = self.compare_views(left_schema_views, right_schema_views)?;
 
-                    entries.push(SchemaMaintained { schema_name: String::from(schema), properties, routines, sequences, tables, views });
-                }
+                    entries.push(SchemaMaintained { schema_name: schema, properties, materialized_views, routines, sequences, tables, types, views });
+                },
+            }
+        }
+
+        Ok(DatabaseComparison { extensions, schemas: Report { entries } })
+    }
+
+    fn compare_extensions(&self, left_extensions: &[Extension], right_extensions: &[Extension]) -> Result<Report<ExtensionComparison>, Error> {
+        let mut right_extensions_map: HashMap<String, &Extension> = right_extensions.iter().map(|e| (e.extension_name.clone(), e)).collect();
+        let mut entries = Vec::new();
+
+        for left_extension in left_extensions {
+            let key = &left_extension.extension_name;
+            let right_extension = right_extensions_map.get(key);
+
+            match right_extension {
+                None => {
+                    entries.push(ExtensionRemoved { extension_name: left_extension.extension_name.clone() });
+                },
+                Some(re) => {
+                    let properties = self.ignore_properties("extension", self.compare_extension_properties(left_extension, re));
+
+                    entries.push(ExtensionMaintained { extension_name: re.extension_name.clone(), properties });
+
+                    right_extensions_map.remove(key);
+                },
+            }
+        }
+
+        if !right_extensions_map.is_empty() {
+            let mut added_extensions: Vec<&&Extension> = right_extensions_map.values().collect();
+            added_extensions.sort_unstable_by_key(|e| &e.extension_name);
+
+            for right_extension in added_extensions {
+                entries.push(ExtensionAdded { extension_name: right_extension.extension_name.clone(), definition: extension_definition(right_extension) });
             }
         }
 
         Ok(Report { entries })
     }
 
+    fn compare_extension_properties(&self, left: &Extension, right: &Extension) -> Report<PropertyComparison> {
+        Report {
+            entries: vec![
+                self.compare_property("extension_version", left, right, |e| &e.extension_version),
+                self.compare_property("extension_schema", left, right, |e| &e.extension_schema),
+                self.compare_property("is_relocatable", left, right, |e| &e.is_relocatable),
+            ]
+        }
+    }
+
     fn compare_schema_properties(&self, left: &Schema, right: &Schema) -> Report<PropertyComparison> {
         Report {
             entries: vec![
@@ -180,7 +346,84 @@ impl Comparer {
         }
     }
 
-    fn compare_routines(&mut self,
+    fn compare_materialized_views(&self,
+                                  left_views: Vec<&MaterializedView>,
+                                  right_views: Vec<&MaterializedView>,
+                                  left_indices: Vec<&Index>,
+                                  right_indices: Vec<&Index>,
+                                  left_privileges: Vec<&MaterializedViewPrivilege>,
+                                  right_privileges: Vec<&MaterializedViewPrivilege>,
+    ) -> Result<Report<MaterializedViewComparison>, Error> {
+        let mut right_views_map: HashMap<String, &MaterializedView> = right_views.into_iter().map(|v| (v.view_name.clone(), v)).collect();
+        let mut entries = Vec::new();
+
+        for left_view in left_views {
+            let key = &left_view.view_name;
+            let right_view = right_views_map.get(key);
+
+            match right_view {
+                None => {
+                    entries.push(MaterializedViewRemoved { view_name: left_view.view_name.clone() });
+                },
+                Some(rv) => {
+                    let properties = self.ignore_properties("materialized_view", self.compare_materialized_view_properties(left_view, rv));
+
+                    let left_view_indices: Vec<&Index> = left_indices.iter().filter(|i| i.table_name == left_view.view_name).cloned().collect();
+                    let right_view_indices: Vec<&Index> = right_indices.iter().filter(|i| i.table_name == rv.view_name).cloned().collect();
+                    let left_view_privileges: Vec<&MaterializedViewPrivilege> = left_privileges.iter().filter(|p| p.view_name == left_view.view_name).cloned().collect();
+                    let right_view_privileges: Vec<&MaterializedViewPrivilege> = right_privileges.iter().filter(|p| p.view_name == rv.view_name).cloned().collect();
+
+                    let definition = materialized_view_definition(rv, &right_view_indices);
+                    let indices = self.compare_table_indices("materialized_view", left_view_indices, right_view_indices)?;
+                    let privileges = self.compare_materialized_view_privileges(left_view_privileges, right_view_privileges)?;
+
+                    entries.push(MaterializedViewMaintained { view_name: rv.view_name.clone(), definition, indices, privileges, properties });
+
+                    right_views_map.remove(key);
+                },
+            }
+        }
+
+        if !right_views_map.is_empty() {
+            let mut added_views: Vec<&&MaterializedView> = right_views_map.values().collect();
+            added_views.sort_unstable_by_key(|v| &v.view_name);
+
+            for right_view in added_views {
+                let right_view_indices: Vec<&Index> = right_indices.iter().filter(|i| i.table_name == right_view.view_name).cloned().collect();
+
+                entries.push(MaterializedViewAdded { view_name: right_view.view_name.clone(), definition: materialized_view_definition(right_view, &right_view_indices) });
+            }
+        }
+
+        Ok(Report { entries })
+    }
+
+    fn compare_materialized_view_properties(&self, left: &MaterializedView, right: &MaterializedView) -> Report<PropertyComparison> {
+        Report {
+            entries: vec![
+                self.compare_property("view_owner", left, right, |v| &v.view_owner),
+                self.compare_option_property("table_space", left, right, |v| &v.table_space),
+                if self.options.normalize_sql {
+                    self.compare_option_property_normalize_sql("view_definition", left, right, |v| &v.view_definition)
+                } else if self.options.ignore_whitespace {
+                    self.compare_option_property_ignore_whitespace("view_definition", left, right, |v| &v.view_definition)
+                } else {
+                    self.compare_option_property("view_definition", left, right, |v| &v.view_definition)
+                },
+                self.compare_property("is_populated", left, right, |v| &v.is_populated),
+            ]
+        }
+    }
+
+    fn compare_materialized_view_privileges(&self, left_privileges: Vec<&MaterializedViewPrivilege>, right_privileges: Vec<&MaterializedViewPrivilege>) -> Result<Report<PrivilegeComparison>, Error> {
+        if self.options.ignore_privileges {
+            return Ok(Report { entries: vec![] })
+        }
+
+        Ok(self.compare_privileges(&left_privileges, &right_privileges))
+    }
+
+    fn compare_routines(&self,
                         left_routines: Vec<&Routine>,
                         right_routines: Vec<&Routine>,
                         left_routine_parameters: Vec<&RoutineParameter>,
@@ -200,25 +443,25 @@ impl Comparer {
                     entries.push(RoutineRemoved { routine_signature: signature.clone() });
                 },
                 Some(rr) => {
-                    let properties = self.compare_routine_properties(&left_routine, rr);
+                    let properties = self.ignore_properties("routine", self.compare_routine_properties(left_routine, rr));
 
                     let left_routine_routine_privileges: Vec<&RoutinePrivilege> = left_routine_privileges.iter().filter(|p| p.specific_catalog == left_routine.specific_catalog && p.specific_schema == left_routine.specific_schema && p.specific_name == left_routine.specific_name).cloned().collect();
                     let right_routine_routine_privileges: Vec<&RoutinePrivilege> = right_routine_privileges.iter().filter(|p| p.specific_catalog == rr.specific_catalog && p.specific_schema == rr.specific_schema && p.specific_name == rr.specific_name).cloned().collect();
                     let privileges = self.compare_routine_privileges(left_routine_routine_privileges, right_routine_routine_privileges)?;
     
-                    entries.push(RoutineMaintained { routine_signature: signature.clone(), properties, privileges });
+                    entries.push(RoutineMaintained { routine_signature: signature.clone(), definition: rr.definition.clone(), properties, privileges });
     
                     right_routines_map.remove(&signature);
                 }
             }
         }
     
-        if right_routines_map.len() > 0 {
-            let mut added_signatures : Vec<&String> = right_routines_map.keys().collect();
-            added_signatures.sort_unstable();
+        if !right_routines_map.is_empty() {
+            let mut added_routines : Vec<(&String, &&Routine)> = right_routines_map.iter().collect();
+            added_routines.sort_unstable_by_key(|(signature, _)| *signature);
     
-            for added_signature in added_signatures {
-                entries.push(RoutineAdded { routine_signature: added_signature.clone() });
+            for (added_signature, added_routine) in added_routines {
+                entries.push(RoutineAdded { routine_signature: added_signature.clone(), definition: added_routine.definition.clone() });
             }
         }
     
@@ -256,10 +499,13 @@ impl Comparer {
                 self.compare_option_property("maximum_cardinality", left, right, |p| &p.maximum_cardinality),
                 self.compare_option_property("dtd_identifier", left, right, |p| &p.dtd_identifier),
                 self.compare_property("routine_body", left, right, |p| &p.routine_body),
-                if self.ignore_whitespace {
-                    self.compare_option_property_ignore_whitespace("routine_definition", left, right, |p| &p.routine_definition)
-                } else {
-                    self.compare_option_property("routine_definition", left, right, |p| &p.routine_definition)
+                match (self.options.compare_routine_definitions, self.options.normalize_sql, self.options.ignore_whitespace) {
+                    (true, true, _) => self.compare_option_property_normalize_sql("definition", left, right, |p| &p.definition),
+                    (true, false, true) => self.compare_option_property_ignore_whitespace("definition", left, right, |p| &p.definition),
+                    (true, false, false) => self.compare_option_property("definition", left, right, |p| &p.definition),
+                    (false, true, _) => self.compare_option_property_normalize_sql("routine_definition", left, right, |p| &p.routine_definition),
+                    (false, false, true) => self.compare_option_property_ignore_whitespace("routine_definition", left, right, |p| &p.routine_definition),
+                    (false, false, false) => self.compare_option_property("routine_definition", left, right, |p| &p.routine_definition),
                 },
                 self.compare_option_property("external_name", left, right, |p| &p.external_name),
                 self.compare_property("external_language", left, right, |p| &p.external_language),
@@ -273,39 +519,47 @@ impl Comparer {
                 self.compare_option_property("is_user_defined_cast", left, right, |p| &p.is_user_defined_cast),
                 self.compare_option_property("is_implicitly_invocable", left, right, |p| &p.is_implicitly_invocable),
                 self.compare_property("security_type", left, right, |p| &p.security_type),
+                self.compare_option_property("volatility", left, right, |p| &p.volatility),
+                self.compare_option_property("is_strict", left, right, |p| &p.is_strict),
+                self.compare_option_property("parallel_safety", left, right, |p| &p.parallel_safety),
+                self.compare_option_property("estimated_cost", left, right, |p| &p.estimated_cost),
+                self.compare_option_property("estimated_rows", left, right, |p| &p.estimated_rows),
+                self.compare_option_property("is_leakproof", left, right, |p| &p.is_leakproof),
+                self.compare_option_property("is_security_definer", left, right, |p| &p.is_security_definer),
+                self.compare_option_property("configuration", left, right, |p| &p.configuration),
             ]
         }
     }
 
-    fn compare_routine_privileges(&mut self, left_routine_privileges: Vec<&RoutinePrivilege>, right_routine_privileges: Vec<&RoutinePrivilege>) -> Result<Report<PrivilegeComparison>, Error> {
-        if self.ignore_privileges {
+    fn compare_routine_privileges(&self, left_routine_privileges: Vec<&RoutinePrivilege>, right_routine_privileges: Vec<&RoutinePrivilege>) -> Result<Report<PrivilegeComparison>, Error> {
+        if self.options.ignore_privileges {
             return Ok(Report { entries: vec![] })
         }
     
         Ok(self.compare_privileges(&left_routine_privileges, &right_routine_privileges))
     }
 
-    fn compare_privileges<P>(&self, left_privileges: &Vec<P>, right_privileges: &Vec<P>) -> Report<PrivilegeComparison> 
+    fn compare_privileges<P>(&self, left_privileges: &[P], right_privileges: &[P]) -> Report<PrivilegeComparison> 
         where P : Privilege
     {
-        let mut right_privileges_map: HashMap<(&str, &str, &str), &P> = right_privileges.into_iter().map(|c| ((c.privilege_type(), c.grantor(), c.grantee()), c)).collect();
+        let mut right_privileges_map: HashMap<(&str, &str, &str), &P> = right_privileges.iter().map(|c| ((c.privilege_type(), c.grantor(), c.grantee()), c)).collect();
         let mut entries = Vec::new();
     
         for left_privilege in left_privileges {
             let key = &(left_privilege.privilege_type(), left_privilege.grantor(), left_privilege.grantee());
-            let right_privilege = right_privileges_map.get(&key);
+            let right_privilege = right_privileges_map.get(key);
     
             match right_privilege {
                 None => entries.push(PrivilegeRemoved { privilege_name: left_privilege.privilege_type().to_string(), grantor: left_privilege.grantor().to_string(), grantee: left_privilege.grantee().to_string() }),
                 Some(..) => {
                     entries.push(PrivilegeMaintained { privilege_name: left_privilege.privilege_type().to_string(), grantor: left_privilege.grantor().to_string(), grantee: left_privilege.grantee().to_string() });
     
-                    _ = right_privileges_map.remove(&key);
+                    _ = right_privileges_map.remove(key);
                 },
             }
         }
     
-        if right_privileges_map.len() > 0 {
+        if !right_privileges_map.is_empty() {
             let mut added_privileges: Vec<&&P> = right_privileges_map.values().collect();
             added_privileges.sort_unstable_by_key(|p| (p.privilege_type(), p.grantor(), p.grantee()));
     
@@ -317,7 +571,7 @@ impl Comparer {
         Report { entries }
     }
 
-    fn compare_sequences(&mut self, left_sequences: Vec<&Sequence>, right_sequences: Vec<&Sequence>) -> Result<Report<SequenceComparison>, Error> {
+    fn compare_sequences(&self, left_sequences: Vec<&Sequence>, right_sequences: Vec<&Sequence>) -> Result<Report<SequenceComparison>, Error> {
         let mut right_sequences_map: HashMap<String, &Sequence> = right_sequences.into_iter().map(|t| (t.sequence_name.clone(), t)).collect();
         let mut entries = Vec::new();
     
@@ -330,7 +584,7 @@ impl Comparer {
                     entries.push(SequenceRemoved { sequence_name: left_sequence.sequence_name.clone() });
                 },
                 Some(rs) => {
-                    let properties = self.compare_sequence_properties(&left_sequence, rs);
+                    let properties = self.ignore_properties("sequence", self.compare_sequence_properties(left_sequence, rs));
     
                     entries.push(SequenceMaintained { sequence_name: left_sequence.sequence_name.clone(), properties });
     
@@ -339,12 +593,12 @@ impl Comparer {
             }
         }
     
-        if right_sequences_map.len() > 0 {
+        if !right_sequences_map.is_empty() {
             let mut added_sequences: Vec<&&Sequence> = right_sequences_map.values().collect();
             added_sequences.sort_unstable_by_key(|s| &s.sequence_name);
     
             for right_sequence in added_sequences {
-                entries.push(SequenceAdded { sequence_name: right_sequence.sequence_name.clone() });
+                entries.push(SequenceAdded { sequence_name: right_sequence.sequence_name.clone(), definition: sequence_definition(right_sequence) });
             }
         }
     
@@ -370,7 +624,8 @@ impl Comparer {
         }
     }
 
-    fn compare_tables(&mut self,
+    #[allow(clippy::too_many_arguments)]
+    fn compare_tables(&self,
                       left_tables: Vec<&Table>,
                       right_tables: Vec<&Table>,
                       left_columns: Vec<&Column>,
@@ -385,9 +640,13 @@ impl Comparer {
                       right_table_constraints : Vec<&TableConstraint>,
                       left_table_triggers : Vec<&TableTrigger>,
                       right_table_triggers : Vec<&TableTrigger>,
+                      left_table_policies : Vec<&TablePolicy>,
+                      right_table_policies : Vec<&TablePolicy>,
     ) -> Result<Report<TableComparison>, Error> {
         let mut entries = Vec::new();
-        let mut right_tables_map: HashMap<String, &Table> = right_tables.into_iter().map(|t| (t.table_name.clone(), t)).collect();
+        let (left_partitions, left_tables): (Vec<&Table>, Vec<&Table>) = left_tables.into_iter().partition(|t| self.is_collapsed(t));
+        let (right_partitions, right_tables): (Vec<&Table>, Vec<&Table>) = right_tables.into_iter().partition(|t| self.is_collapsed(t));
+        let mut right_tables_map: HashMap<String, &Table> = right_tables.iter().map(|t| (t.table_name.clone(), *t)).collect();
 
         for left_table in left_tables {
             let key = &left_table.table_name;
@@ -395,10 +654,13 @@ impl Comparer {
     
             match right_table {
                 None => {
-                    entries.push(TableRemoved { table_name: left_table.table_name.clone() });
+                    // reported by view comparison
+                    if left_table.table_type != "VIEW" {
+                        entries.push(TableRemoved { table_name: left_table.table_name.clone(), table_type: left_table.table_type.clone() });
+                    }
                 },
                 Some(rt) => {
-                    let properties = self.compare_table_properties(&left_table, rt);
+                    let properties = self.ignore_properties("table", self.compare_table_properties(left_table, rt));
 
                     let left_table_columns : Vec<&Column> = left_columns.iter().filter(|t| t.table_name == left_table.table_name).cloned().collect();
                     let right_table_columns : Vec<&Column> = right_columns.iter().filter(|t| t.table_name == rt.table_name).cloned().collect();
@@ -412,26 +674,42 @@ impl Comparer {
                     let right_table_table_constraints: Vec<&TableConstraint> = right_table_constraints.iter().filter(|c| c.table_name == left_table.table_name).cloned().collect();
                     let left_table_table_triggers : Vec<&TableTrigger> = left_table_triggers.iter().filter(|t| t.event_object_table == left_table.table_name).cloned().collect();
                     let right_table_table_triggers : Vec<&TableTrigger> = right_table_triggers.iter().filter(|t| t.event_object_table == rt.table_name).cloned().collect();
+                    let left_table_table_policies : Vec<&TablePolicy> = left_table_policies.iter().filter(|p| p.table_name == left_table.table_name).cloned().collect();
+                    let right_table_table_policies : Vec<&TablePolicy> = right_table_policies.iter().filter(|p| p.table_name == rt.table_name).cloned().collect();
                     
                     let columns = self.compare_table_columns(left_table_columns, right_table_columns, left_table_column_privileges, right_table_column_privileges)?;
-                    let indices = self.compare_table_indices(left_table_indices, right_table_indices)?;
+                    let indices = self.compare_table_indices("table", left_table_indices, right_table_indices)?;
                     let privileges = self.compare_table_privileges(left_table_table_privileges, right_table_table_privileges)?;
                     let constraints = self.compare_table_constraints(left_table_table_constraints, right_table_table_constraints)?;
                     let triggers = self.compare_table_triggers(left_table_table_triggers, right_table_table_triggers)?;
+                    let policies = self.compare_table_policies(left_table_table_policies, right_table_table_policies)?;
+                    let partitions = self.compare_table_partitions(partition_tree(left_table, &left_partitions), partition_tree(rt, &right_partitions))?;
     
-                    entries.push(TableMaintained { table_name: left_table.table_name.clone(), columns, constraints, indices, privileges, properties, triggers });
+                    entries.push(TableMaintained { table_name: left_table.table_name.clone(), columns, constraints, indices, partitions, policies, privileges, properties, triggers });
     
                     right_tables_map.remove(key);
                 },
             }
         }
     
-        if right_tables_map.len() > 0 {
-            let mut added_tables: Vec<&&Table> = right_tables_map.values().collect();
-            added_tables.sort_unstable_by_key(|t| &t.table_name);
+        if !right_tables_map.is_empty() {
+            let mut added_tables: Vec<&&Table> = right_tables_map.values().filter(|t| t.table_type != "VIEW").collect();
+            // partitions can only be created once their parent exists
+            added_tables.sort_unstable_by_key(|t| (partition_depth(t, &right_tables), &t.table_name));
     
             for right_table in added_tables {
-                entries.push(TableAdded { table_name: right_table.table_name.clone() });
+                let right_table_columns: Vec<&Column> = right_columns.iter().filter(|c| c.table_name == right_table.table_name).cloned().collect();
+                let right_table_constraints: Vec<&TableConstraint> = right_table_constraints.iter().filter(|c| c.table_name == right_table.table_name).cloned().collect();
+                let right_table_indices: Vec<&Index> = right_indices.iter().filter(|i| i.table_name == right_table.table_name).cloned().collect();
+                let right_table_triggers: Vec<&TableTrigger> = right_table_triggers.iter().filter(|t| t.event_object_table == right_table.table_name).cloned().collect();
+                let right_table_policies: Vec<&TablePolicy> = right_table_policies.iter().filter(|p| p.table_name == right_table.table_name).cloned().collect();
+                let mut definition = table_definition(right_table, &right_table_columns, &right_table_constraints, &right_table_indices, &right_table_triggers, &right_table_policies);
+
+                for partition in partition_tree(right_table, &right_partitions) {
+                    definition.push_str(&format!(";\n{}", partition_definition(partition)));
+                }
+
+                entries.push(TableAdded { table_name: right_table.table_name.clone(), table_type: right_table.table_type.clone(), definition });
             }
         }
     
@@ -453,19 +731,74 @@ impl Comparer {
                 self.compare_property("is_insertable_into", left, right, |c| &c.is_insertable_into),
                 self.compare_property("is_typed", left, right, |c| &c.is_typed),
                 self.compare_option_property("commit_action", left, right, |c| &c.commit_action),
+                self.compare_property("row_security", left, right, |c| &c.row_security),
+                self.compare_property("force_row_security", left, right, |c| &c.force_row_security),
+                self.compare_option_property("partition_strategy", left, right, |c| &c.partition_strategy),
+                self.compare_option_property("partition_key", left, right, |c| &c.partition_key),
+                self.compare_option_property("partition_parent_schema", left, right, |c| &c.partition_parent_schema),
+                self.compare_option_property("partition_parent_name", left, right, |c| &c.partition_parent_name),
+                self.compare_option_property("partition_bound", left, right, |c| &c.partition_bound),
+                self.compare_option_property("parents", left, right, |c| &c.parents),
             ]
         }
     }
 
-    fn compare_table_privileges(&mut self, left_table_privileges: Vec<&TablePrivilege>, right_table_privileges: Vec<&TablePrivilege>) -> Result<Report<PrivilegeComparison>, Error> {
-        if self.ignore_privileges {
+    fn is_collapsed(&self, table: &Table) -> bool {
+        self.options.collapse_partitions && table.partition_parent_name.is_some()
+    }
+
+    fn compare_table_partitions(&self, left_partitions: Vec<&Table>, right_partitions: Vec<&Table>) -> Result<Report<PartitionComparison>, Error> {
+        let mut right_partitions_map: HashMap<String, &Table> = right_partitions.iter().map(|p| (p.table_name.clone(), *p)).collect();
+        let mut entries = Vec::new();
+
+        for left_partition in left_partitions {
+            let key = &left_partition.table_name;
+            let right_partition = right_partitions_map.get(key);
+
+            match right_partition {
+                None => {
+                    entries.push(PartitionRemoved { partition_name: left_partition.table_name.clone() });
+                },
+                Some(rp) => {
+                    let properties = self.ignore_properties("table.partition", self.compare_partition_properties(left_partition, rp));
+
+                    entries.push(PartitionMaintained { partition_name: rp.table_name.clone(), properties });
+
+                    right_partitions_map.remove(key);
+                },
+            }
+        }
+
+        // added partitions keep the order of the partition tree, so parents are created first
+        for right_partition in right_partitions {
+            if right_partitions_map.contains_key(&right_partition.table_name) {
+                entries.push(PartitionAdded { partition_name: right_partition.table_name.clone(), definition: partition_definition(right_partition) });
+            }
+        }
+
+        Ok(Report { entries })
+    }
+
+    fn compare_partition_properties(&self, left: &Table, right: &Table) -> Report<PropertyComparison> {
+        Report {
+            entries: vec![
+                self.compare_option_property("partition_parent_name", left, right, |p| &p.partition_parent_name),
+                self.compare_option_property("partition_bound", left, right, |p| &p.partition_bound),
+                self.compare_option_property("partition_strategy", left, right, |p| &p.partition_strategy),
+                self.compare_option_property("partition_key", left, right, |p| &p.partition_key),
+            ]
+        }
+    }
+
+    fn compare_table_privileges(&self, left_table_privileges: Vec<&TablePrivilege>, right_table_privileges: Vec<&TablePrivilege>) -> Result<Report<PrivilegeComparison>, Error> {
+        if self.options.ignore_privileges {
             return Ok(Report { entries: vec![] })
         }
     
         Ok(self.compare_privileges(&left_table_privileges, &right_table_privileges))
     }
 
-    fn compare_table_columns(&mut self,
+    fn compare_table_columns(&self,
                              left_columns: Vec<&Column>,
                              right_columns: Vec<&Column>,
                              left_column_privileges: Vec<&ColumnPrivilege>,
@@ -483,7 +816,7 @@ impl Comparer {
                     entries.push(ColumnRemoved { column_name: left_column.column_name.clone() });
                 },
                 Some(rc) => {
-                    let properties = self.compare_table_column_properties(&left_column, rc);
+                    let properties = self.ignore_properties("table.column", self.compare_table_column_properties(left_column, rc));
                     
                     let left_column_privileges = left_column_privileges.iter().filter(|p| p.column_name == left_column.column_name).cloned().collect();
                     let right_column_privileges = right_column_privileges.iter().filter(|p| p.column_name == rc.column_name).cloned().collect();
@@ -496,12 +829,12 @@ impl Comparer {
             }
         }
     
-        if right_columns_map.len() > 0 {
+        if !right_columns_map.is_empty() {
             let mut added_columns : Vec<&&Column> = right_columns_map.values().collect();
             added_columns.sort_unstable_by_key(|c| &c.column_name);
     
             for right_column in added_columns {
-                entries.push(ColumnAdded { column_name: right_column.column_name.clone() });
+                entries.push(ColumnAdded { column_name: right_column.column_name.clone(), definition: column_definition(right_column) });
             }
         }
     
@@ -510,9 +843,14 @@ impl Comparer {
     
     fn compare_table_column_properties(&self, left: &Column, right: &Column) -> Report<PropertyComparison> {
         let mut properties = vec![
-            self.compare_option_property("column_default", left, right, |c| &c.column_default),
+            if self.options.normalize_sql {
+                self.compare_option_property_normalize_sql("column_default", left, right, |c| &c.column_default)
+            } else {
+                self.compare_option_property("column_default", left, right, |c| &c.column_default)
+            },
             self.compare_property("is_nullable", left, right, |c| &c.is_nullable),
             self.compare_property("data_type", left, right, |c| &c.data_type),
+            self.compare_property("column_type", left, right, |c| &c.column_type),
             self.compare_option_property("character_maximum_length", left, right, |c| &c.character_maximum_length),
             self.compare_option_property("character_octet_length", left, right, |c| &c.character_octet_length),
             self.compare_option_property("numeric_precision", left, right, |c| &c.numeric_precision),
@@ -547,26 +885,35 @@ impl Comparer {
             self.compare_option_property("identity_minimum", left, right, |c| &c.identity_minimum),
             self.compare_option_property("identity_cycle", left, right, |c| &c.identity_cycle),
             self.compare_property("is_generated", left, right, |c| &c.is_generated),
-            self.compare_option_property("generation_expression", left, right, |c| &c.generation_expression),
+            if self.options.normalize_sql {
+                self.compare_option_property_normalize_sql("generation_expression", left, right, |c| &c.generation_expression)
+            } else {
+                self.compare_option_property("generation_expression", left, right, |c| &c.generation_expression)
+            },
             self.compare_property("is_updatable", left, right, |c| &c.is_updatable),
         ];
     
-        if !self.ignore_column_ordinal {
+        if !self.options.ignore_column_ordinal {
             properties.push(self.compare_property("ordinal_position", left, right, |c| &c.ordinal_position));
         }
+
+        if self.options.distinguish_inherited_columns {
+            properties.push(self.compare_property("is_local", left, right, |c| &c.is_local));
+            properties.push(self.compare_property("inheritance_count", left, right, |c| &c.inheritance_count));
+        }
     
         Report { entries: properties }
     }
 
-    fn compare_table_column_privileges(&mut self, left_column_privileges: Vec<&ColumnPrivilege>, right_column_privileges: Vec<&ColumnPrivilege>) -> Result<Report<PrivilegeComparison>, Error> {
-        if self.ignore_privileges {
+    fn compare_table_column_privileges(&self, left_column_privileges: Vec<&ColumnPrivilege>, right_column_privileges: Vec<&ColumnPrivilege>) -> Result<Report<PrivilegeComparison>, Error> {
+        if self.options.ignore_privileges {
             return Ok(Report { entries: vec![] })
         }
     
         Ok(self.compare_privileges(&left_column_privileges, &right_column_privileges))
     }
 
-    fn compare_table_constraints(&mut self, left_table_constraints: Vec<&TableConstraint>, right_table_constraints: Vec<&TableConstraint>) -> Result<Report<TableConstraintComparison>, Error> {
+    fn compare_table_constraints(&self, left_table_constraints: Vec<&TableConstraint>, right_table_constraints: Vec<&TableConstraint>) -> Result<Report<TableConstraintComparison>, Error> {
         let mut right_table_constraints_map : HashMap<String, &TableConstraint> = right_table_constraints.into_iter().map(|t| (t.constraint_name.clone(), t)).collect();
         let mut entries = Vec::new();
     
@@ -579,7 +926,7 @@ impl Comparer {
                     entries.push(ConstraintRemoved { constraint_name: left_table_constraint.clone().constraint_name });
                 },
                 Some(rtc) => {
-                    let properties = self.compare_table_constraint_properties(&left_table_constraint, rtc);
+                    let properties = self.ignore_properties("table.constraint", self.compare_table_constraint_properties(left_table_constraint, rtc));
     
                     entries.push(ConstraintMaintained { constraint_name: rtc.constraint_name.clone(), properties });
     
@@ -588,19 +935,19 @@ impl Comparer {
             }
         }
     
-        if right_table_constraints_map.len() > 0 {
+        if !right_table_constraints_map.is_empty() {
             let mut added_table_constraints : Vec<&&TableConstraint> = right_table_constraints_map.values().collect();
             added_table_constraints.sort_unstable_by_key(|tc| &tc.constraint_name);
     
             for right_table_constraint in added_table_constraints {
-                entries.push(ConstraintAdded { constraint_name: right_table_constraint.constraint_name.clone() });
+                entries.push(ConstraintAdded { constraint_name: right_table_constraint.constraint_name.clone(), definition: right_table_constraint.definition.clone() });
             }
         }
     
         Ok(Report { entries })
     }
 
-    fn compare_table_constraint_properties(&mut self, left: &TableConstraint, right: &TableConstraint) -> Report<PropertyComparison> {
+    fn compare_table_constraint_properties(&self, left: &TableConstraint, right: &TableConstraint) -> Report<PropertyComparison> {
         Report {
             entries: vec![
                 self.compare_property("constraint_catalog", left, right, |c| &c.constraint_catalog),
@@ -611,11 +958,24 @@ impl Comparer {
                 self.compare_property("initially_deferred", left, right, |c| &c.initially_deferred),
                 self.compare_property("enforced", left, right, |c| &c.enforced),
                 self.compare_option_property("nulls_distinct", left, right, |c| &c.nulls_distinct),
+                if self.options.ignore_whitespace {
+                    self.compare_option_property_ignore_whitespace("definition", left, right, |c| &c.definition)
+                } else {
+                    self.compare_option_property("definition", left, right, |c| &c.definition)
+                },
+                self.compare_option_property("key_columns", left, right, |c| &c.key_columns),
+                self.compare_option_property("referenced_table_schema", left, right, |c| &c.referenced_table_schema),
+                self.compare_option_property("referenced_table_name", left, right, |c| &c.referenced_table_name),
+                self.compare_option_property("referenced_columns", left, right, |c| &c.referenced_columns),
+                self.compare_option_property("match_option", left, right, |c| &c.match_option),
+                self.compare_option_property("update_rule", left, right, |c| &c.update_rule),
+                self.compare_option_property("delete_rule", left, right, |c| &c.delete_rule),
             ]
         }
     }
 
-    fn compare_table_indices(&mut self,
+    fn compare_table_indices(&self,
+                             parent_kind: &str,
                              left_indices: Vec<&Index>,
                              right_indices: Vec<&Index>,
     ) -> Result<Report<IndexComparison>, Error> {
@@ -631,7 +991,7 @@ impl Comparer {
                     entries.push(IndexRemoved { index_name: left_index.index_name.clone() });
                 },
                 Some(ri) => {
-                    let properties = self.compare_table_index_properties(&left_index, ri);
+                    let properties = self.ignore_properties(&format!("{}.index", parent_kind), self.compare_table_index_properties(left_index, ri));
 
                     entries.push(IndexMaintained { index_name: ri.index_name.clone(), properties });
 
@@ -640,27 +1000,31 @@ impl Comparer {
             }
         }
 
-        if right_indices_map.len() > 0 {
+        if !right_indices_map.is_empty() {
             let mut added_indices: Vec<&&Index> = right_indices_map.values().collect();
             added_indices.sort_unstable_by_key(|i| &i.index_name);
 
             for right_index in added_indices {
-                entries.push(IndexAdded { index_name: right_index.index_name.clone() });
+                entries.push(IndexAdded { index_name: right_index.index_name.clone(), definition: right_index.definition.clone() });
             }
         }
 
         Ok(Report { entries })
     }
     
-    fn compare_table_index_properties(&mut self, left: &Index, right: &Index) -> Report<PropertyComparison> {
+    fn compare_table_index_properties(&self, left: &Index, right: &Index) -> Report<PropertyComparison> {
         Report {
             entries: vec![
-                self.compare_property("definition", left, right, |i| &i.definition),
+                if self.options.normalize_sql {
+                    self.compare_property_normalize_sql("definition", left, right, |i| &i.definition)
+                } else {
+                    self.compare_property("definition", left, right, |i| &i.definition)
+                },
             ]
         }
     }
 
-    fn compare_table_triggers(&mut self, left_table_triggers: Vec<&TableTrigger>, right_table_triggers: Vec<&TableTrigger>) -> Result<Report<TableTriggerComparison>, Error> {
+    fn compare_table_triggers(&self, left_table_triggers: Vec<&TableTrigger>, right_table_triggers: Vec<&TableTrigger>) -> Result<Report<TableTriggerComparison>, Error> {
         let mut right_triggers_map : HashMap<(String, String), &TableTrigger> = right_table_triggers.into_iter().map(|t| ((t.trigger_name.clone(), t.event_manipulation.clone()), t)).collect();
         let mut entries = Vec::new();
     
@@ -673,35 +1037,39 @@ impl Comparer {
                     entries.push(TriggerRemoved { trigger_name: left_table_trigger.trigger_name.clone(), event_manipulation: left_table_trigger.event_manipulation.clone() });
                 },
                 Some(rt) => {
-                    let properties = self.compare_trigger_properties(&left_table_trigger, rt);
+                    let properties = self.ignore_properties("table.trigger", self.compare_trigger_properties(left_table_trigger, rt));
     
-                    entries.push(TriggerMaintained { trigger_name: rt.trigger_name.clone(), event_manipulation: rt.event_manipulation.clone(), properties });
+                    entries.push(TriggerMaintained { trigger_name: rt.trigger_name.clone(), event_manipulation: rt.event_manipulation.clone(), definition: rt.definition.clone(), properties });
     
                     right_triggers_map.remove(&key);
                 },
             }
         }
     
-        if right_triggers_map.len() > 0 {
+        if !right_triggers_map.is_empty() {
             let mut added_triggers : Vec<&&TableTrigger> = right_triggers_map.values().collect();
             added_triggers.sort_unstable_by_key(|t| (&t.trigger_name, &t.event_manipulation));
     
             for right_trigger in added_triggers {
-                entries.push(TriggerAdded { trigger_name: right_trigger.trigger_name.clone(), event_manipulation: right_trigger.event_manipulation.clone() });
+                entries.push(TriggerAdded { trigger_name: right_trigger.trigger_name.clone(), event_manipulation: right_trigger.event_manipulation.clone(), definition: right_trigger.definition.clone() });
             }
         }
     
         Ok(Report { entries })
     }
 
-    fn compare_trigger_properties(&mut self, left: &TableTrigger, right: &TableTrigger) -> Report<PropertyComparison> {
+    fn compare_trigger_properties(&self, left: &TableTrigger, right: &TableTrigger) -> Report<PropertyComparison> {
         Report {
             entries: vec![
                 self.compare_property("trigger_catalog", left, right, |t| &t.trigger_catalog),
                 self.compare_property("trigger_schema", left, right, |t| &t.trigger_schema),
                 self.compare_property("action_order", left, right, |t| &t.action_order),
                 self.compare_option_property("action_condition", left, right, |t| &t.action_condition),
-                self.compare_property("action_statement", left, right, |t| &t.action_statement),
+                if self.options.normalize_sql {
+                    self.compare_property_normalize_sql("action_statement", left, right, |t| &t.action_statement)
+                } else {
+                    self.compare_property("action_statement", left, right, |t| &t.action_statement)
+                },
                 self.compare_property("action_orientation", left, right, |t| &t.action_orientation),
                 self.compare_property("action_timing", left, right, |t| &t.action_timing),
                 self.compare_option_property("action_reference_old_table", left, right, |t| &t.action_reference_old_table),
@@ -710,35 +1078,277 @@ impl Comparer {
         }
     }
 
-    fn compare_views(&mut self, left_views: Vec<&View>, right_views: Vec<&View>) -> Result<Report<ViewComparison>, Error> {
-        let right_views_map: HashMap<String, &View> = right_views.into_iter().map(|t| (t.table_name.clone(), t)).collect();
+    #[allow(clippy::too_many_arguments)]
+    fn compare_user_types(&self,
+                          left_user_types: Vec<&UserType>,
+                          right_user_types: Vec<&UserType>,
+                          left_user_type_attributes: Vec<&UserTypeAttribute>,
+                          right_user_type_attributes: Vec<&UserTypeAttribute>,
+                          left_user_type_constraints: Vec<&UserTypeConstraint>,
+                          right_user_type_constraints: Vec<&UserTypeConstraint>,
+                          left_user_type_privileges: Vec<&UserTypePrivilege>,
+                          right_user_type_privileges: Vec<&UserTypePrivilege>,
+    ) -> Result<Report<UserTypeComparison>, Error> {
+        let mut right_user_types_map: HashMap<String, &UserType> = right_user_types.into_iter().map(|t| (t.type_name.clone(), t)).collect();
+        let mut entries = Vec::new();
+
+        for left_user_type in left_user_types {
+            let key = &left_user_type.type_name;
+            let right_user_type = right_user_types_map.get(key);
+
+            match right_user_type {
+                None => {
+                    entries.push(UserTypeRemoved { type_name: left_user_type.type_name.clone(), type_kind: left_user_type.type_kind.clone() });
+                },
+                Some(rt) => {
+                    let properties = self.ignore_properties("type", self.compare_user_type_properties(left_user_type, rt));
+
+                    let left_type_attributes: Vec<&UserTypeAttribute> = left_user_type_attributes.iter().filter(|a| a.type_name == left_user_type.type_name).cloned().collect();
+                    let right_type_attributes: Vec<&UserTypeAttribute> = right_user_type_attributes.iter().filter(|a| a.type_name == rt.type_name).cloned().collect();
+                    let left_type_constraints: Vec<&UserTypeConstraint> = left_user_type_constraints.iter().filter(|c| c.type_name == left_user_type.type_name).cloned().collect();
+                    let right_type_constraints: Vec<&UserTypeConstraint> = right_user_type_constraints.iter().filter(|c| c.type_name == rt.type_name).cloned().collect();
+                    let left_type_privileges: Vec<&UserTypePrivilege> = left_user_type_privileges.iter().filter(|p| p.type_name == left_user_type.type_name).cloned().collect();
+                    let right_type_privileges: Vec<&UserTypePrivilege> = right_user_type_privileges.iter().filter(|p| p.type_name == rt.type_name).cloned().collect();
+
+                    let attributes = self.compare_user_type_attributes(left_type_attributes, right_type_attributes)?;
+                    let constraints = self.compare_user_type_constraints(left_type_constraints, right_type_constraints)?;
+                    let privileges = self.compare_user_type_privileges(left_type_privileges, right_type_privileges)?;
+
+                    entries.push(UserTypeMaintained { type_name: rt.type_name.clone(), attributes, constraints, privileges, properties });
+
+                    right_user_types_map.remove(key);
+                },
+            }
+        }
+
+        if !right_user_types_map.is_empty() {
+            let mut added_user_types: Vec<&&UserType> = right_user_types_map.values().collect();
+            added_user_types.sort_unstable_by_key(|t| &t.type_name);
+
+            for right_user_type in added_user_types {
+                let right_type_attributes: Vec<&UserTypeAttribute> = right_user_type_attributes.iter().filter(|a| a.type_name == right_user_type.type_name).cloned().collect();
+                let right_type_constraints: Vec<&UserTypeConstraint> = right_user_type_constraints.iter().filter(|c| c.type_name == right_user_type.type_name).cloned().collect();
+                let definition = user_type_definition(right_user_type, &right_type_attributes, &right_type_constraints);
+
+                entries.push(UserTypeAdded { type_name: right_user_type.type_name.clone(), type_kind: right_user_type.type_kind.clone(), definition });
+            }
+        }
+
+        Ok(Report { entries })
+    }
+
+    fn compare_user_type_properties(&self, left: &UserType, right: &UserType) -> Report<PropertyComparison> {
+        Report {
+            entries: vec![
+                self.compare_property("type_kind", left, right, |t| &t.type_kind),
+                self.compare_property("type_owner", left, right, |t| &t.type_owner),
+                self.compare_option_property("enum_labels", left, right, |t| &t.enum_labels),
+                self.compare_option_property("base_type", left, right, |t| &t.base_type),
+                self.compare_option_property("default_value", left, right, |t| &t.default_value),
+                self.compare_property("is_not_null", left, right, |t| &t.is_not_null),
+                self.compare_option_property("range_subtype", left, right, |t| &t.range_subtype),
+            ]
+        }
+    }
+
+    fn compare_user_type_attributes(&self, left_attributes: Vec<&UserTypeAttribute>, right_attributes: Vec<&UserTypeAttribute>) -> Result<Report<UserTypeAttributeComparison>, Error> {
+        let mut right_attributes_map: HashMap<String, &UserTypeAttribute> = right_attributes.into_iter().map(|a| (a.attribute_name.clone(), a)).collect();
+        let mut entries = Vec::new();
+
+        for left_attribute in left_attributes {
+            let key = &left_attribute.attribute_name;
+            let right_attribute = right_attributes_map.get(key);
+
+            match right_attribute {
+                None => {
+                    entries.push(AttributeRemoved { attribute_name: left_attribute.attribute_name.clone() });
+                },
+                Some(ra) => {
+                    let properties = self.ignore_properties("type.attribute", self.compare_user_type_attribute_properties(left_attribute, ra));
+
+                    entries.push(AttributeMaintained { attribute_name: ra.attribute_name.clone(), properties });
+
+                    right_attributes_map.remove(key);
+                },
+            }
+        }
+
+        if !right_attributes_map.is_empty() {
+            let mut added_attributes: Vec<&&UserTypeAttribute> = right_attributes_map.values().collect();
+            added_attributes.sort_unstable_by_key(|a| a.ordinal_position);
+
+            for right_attribute in added_attributes {
+                entries.push(AttributeAdded { attribute_name: right_attribute.attribute_name.clone(), definition: user_type_attribute_definition(right_attribute) });
+            }
+        }
+
+        Ok(Report { entries })
+    }
+
+    fn compare_user_type_attribute_properties(&self, left: &UserTypeAttribute, right: &UserTypeAttribute) -> Report<PropertyComparison> {
+        let mut properties = vec![
+            self.compare_property("data_type", left, right, |a| &a.data_type),
+            self.compare_option_property("collation_name", left, right, |a| &a.collation_name),
+        ];
+
+        if !self.options.ignore_column_ordinal {
+            properties.push(self.compare_property("ordinal_position", left, right, |a| &a.ordinal_position));
+        }
+
+        Report { entries: properties }
+    }
+
+    fn compare_user_type_constraints(&self, left_constraints: Vec<&UserTypeConstraint>, right_constraints: Vec<&UserTypeConstraint>) -> Result<Report<TableConstraintComparison>, Error> {
+        let mut right_constraints_map: HashMap<String, &UserTypeConstraint> = right_constraints.into_iter().map(|c| (c.constraint_name.clone(), c)).collect();
+        let mut entries = Vec::new();
+
+        for left_constraint in left_constraints {
+            let key = &left_constraint.constraint_name;
+            let right_constraint = right_constraints_map.get(key);
+
+            match right_constraint {
+                None => {
+                    entries.push(ConstraintRemoved { constraint_name: left_constraint.constraint_name.clone() });
+                },
+                Some(rc) => {
+                    let properties = self.ignore_properties("type.constraint", self.compare_user_type_constraint_properties(left_constraint, rc));
+
+                    entries.push(ConstraintMaintained { constraint_name: rc.constraint_name.clone(), properties });
+
+                    right_constraints_map.remove(key);
+                },
+            }
+        }
+
+        if !right_constraints_map.is_empty() {
+            let mut added_constraints: Vec<&&UserTypeConstraint> = right_constraints_map.values().collect();
+            added_constraints.sort_unstable_by_key(|c| &c.constraint_name);
+
+            for right_constraint in added_constraints {
+                entries.push(ConstraintAdded { constraint_name: right_constraint.constraint_name.clone(), definition: Some(right_constraint.definition.clone()) });
+            }
+        }
+
+        Ok(Report { entries })
+    }
+
+    fn compare_user_type_constraint_properties(&self, left: &UserTypeConstraint, right: &UserTypeConstraint) -> Report<PropertyComparison> {
+        Report {
+            entries: vec![
+                if self.options.ignore_whitespace {
+                    self.compare_property_ignore_whitespace("definition", left, right, |c| &c.definition)
+                } else {
+                    self.compare_property("definition", left, right, |c| &c.definition)
+                },
+                self.compare_property("is_validated", left, right, |c| &c.is_validated),
+            ]
+        }
+    }
+
+    fn compare_user_type_privileges(&self, left_user_type_privileges: Vec<&UserTypePrivilege>, right_user_type_privileges: Vec<&UserTypePrivilege>) -> Result<Report<PrivilegeComparison>, Error> {
+        if self.options.ignore_privileges {
+            return Ok(Report { entries: vec![] })
+        }
+
+        Ok(self.compare_privileges(&left_user_type_privileges, &right_user_type_privileges))
+    }
+
+    fn compare_table_policies(&self, left_table_policies: Vec<&TablePolicy>, right_table_policies: Vec<&TablePolicy>) -> Result<Report<PolicyComparison>, Error> {
+        let mut right_policies_map: HashMap<String, &TablePolicy> = right_table_policies.into_iter().map(|p| (p.policy_name.clone(), p)).collect();
+        let mut entries = Vec::new();
+
+        for left_policy in left_table_policies {
+            let key = &left_policy.policy_name;
+            let right_policy = right_policies_map.get(key);
+
+            match right_policy {
+                None => {
+                    entries.push(PolicyRemoved { policy_name: left_policy.policy_name.clone() });
+                },
+                Some(rp) => {
+                    let properties = self.ignore_properties("table.policy", self.compare_policy_properties(left_policy, rp));
+
+                    entries.push(PolicyMaintained { policy_name: rp.policy_name.clone(), definition: policy_definition(rp), properties });
+
+                    right_policies_map.remove(key);
+                },
+            }
+        }
+
+        if !right_policies_map.is_empty() {
+            let mut added_policies: Vec<&&TablePolicy> = right_policies_map.values().collect();
+            added_policies.sort_unstable_by_key(|p| &p.policy_name);
+
+            for right_policy in added_policies {
+                entries.push(PolicyAdded { policy_name: right_policy.policy_name.clone(), definition: policy_definition(right_policy) });
+            }
+        }
+
+        Ok(Report { entries })
+    }
+
+    fn compare_policy_properties(&self, left: &TablePolicy, right: &TablePolicy) -> Report<PropertyComparison> {
+        Report {
+            entries: vec![
+                self.compare_property("command", left, right, |p| &p.command),
+                self.compare_property("permissive", left, right, |p| &p.permissive),
+                self.compare_property("roles", left, right, |p| &p.roles),
+                if self.options.ignore_whitespace {
+                    self.compare_option_property_ignore_whitespace("using_expression", left, right, |p| &p.using_expression)
+                } else {
+                    self.compare_option_property("using_expression", left, right, |p| &p.using_expression)
+                },
+                if self.options.ignore_whitespace {
+                    self.compare_option_property_ignore_whitespace("check_expression", left, right, |p| &p.check_expression)
+                } else {
+                    self.compare_option_property("check_expression", left, right, |p| &p.check_expression)
+                },
+            ]
+        }
+    }
+
+    fn compare_views(&self, left_views: Vec<&View>, right_views: Vec<&View>) -> Result<Report<ViewComparison>, Error> {
+        let mut right_views_map: HashMap<String, &View> = right_views.into_iter().map(|t| (t.table_name.clone(), t)).collect();
         let mut entries = Vec::new();
     
         for left_view in left_views {
-            let right_view = right_views_map.get(&left_view.table_name);
+            let key = &left_view.table_name;
+            let right_view = right_views_map.get(key);
     
             match right_view {
                 None => {
-                    // already detected by table comparison
-                    continue
+                    entries.push(ViewRemoved { view_name: left_view.table_name.clone() });
                 },
                 Some(rv) => {
-                    let properties = self.compare_view_properties(&left_view, rv);
+                    let properties = self.ignore_properties("view", self.compare_view_properties(left_view, rv));
     
                     entries.push(ViewMaintained { view_name: rv.table_name.clone(), properties });
+
+                    right_views_map.remove(key);
                 }
             }
         }
     
-        // added already detected by table comparison
+        if !right_views_map.is_empty() {
+            let mut added_views: Vec<&&View> = right_views_map.values().collect();
+            added_views.sort_unstable_by_key(|v| &v.table_name);
+
+            for right_view in added_views {
+                entries.push(ViewAdded { view_name: right_view.table_name.clone(), definition: view_definition(right_view) });
+            }
+        }
     
         Ok(Report { entries })
     }
 
-    fn compare_view_properties(&mut self, left: &View, right: &View) -> Report<PropertyComparison> {
+    fn compare_view_properties(&self, left: &View, right: &View) -> Report<PropertyComparison> {
         Report {
             entries: vec![
-                self.compare_option_property("view_definition", left, right, |c| &c.view_definition),
+                if self.options.normalize_sql {
+                    self.compare_option_property_normalize_sql("view_definition", left, right, |c| &c.view_definition)
+                } else {
+                    self.compare_option_property("view_definition", left, right, |c| &c.view_definition)
+                },
                 self.compare_property("check_option", left, right, |c| &c.check_option),
                 self.compare_property("is_updatable", left, right, |c| &c.is_updatable),
                 self.compare_property("is_insertable_into", left, right, |c| &c.is_insertable_into),
@@ -749,6 +1359,38 @@ impl Comparer {
         }
     }
 
+    // gives each left schema its own copy of the right schema mapped to it, renamed to the left name, so that several
+    // left schemas, such as tenants, can be compared with one right schema, such as their template
+    async fn map_schemas(&self, right: Catalog, schemas: &[String]) -> Result<Catalog, Error> {
+        let mut mapped = Catalog { extensions: right.extensions.clone(), ..Catalog::default() };
+
+        for schema in schemas {
+            let right_name = self.options.schema_map.get(schema).unwrap_or(schema);
+            let mut copy = Catalog::capture(&right, std::slice::from_ref(right_name)).await?;
+            copy.rename_schemas(&HashMap::from([(right_name.clone(), schema.clone())]));
+            mapped.append(copy);
+        }
+
+        Ok(mapped)
+    }
+
+    // reports changes to properties whose path, such as `table.column.collation_name`, matches an ignored pattern as
+    // unchanged, keeping the left value
+    fn ignore_properties(&self, object_path: &str, properties: Report<PropertyComparison>) -> Report<PropertyComparison> {
+        let is_ignored = |property_name: &str| {
+            self.options.ignore_properties.iter().any(|pattern| matches_property_path(pattern, object_path, property_name))
+        };
+
+        Report {
+            entries: properties.entries.into_iter()
+                .map(|property| match property {
+                    PropertyChanged { property_name, left_value, .. } if is_ignored(&property_name) => PropertyUnchanged { property_name, value: left_value },
+                    property => property,
+                })
+                .collect()
+        }
+    }
+
     fn compare_property<'a, T, P>(&self, property_name: &str, left: T, right: T, accessor: fn(T) -> &'a P) -> PropertyComparison
         where P: PartialEq, P: Display
     {
@@ -756,9 +1398,35 @@ impl Comparer {
         let right_value = accessor(right);
         
         if left_value == right_value {
-            PropertyUnchanged { property_name: String::from(property_name), value: left_value.to_string() }
+            PropertyUnchanged { property_name: String::from(property_name), value: Some(left_value.to_string()) }
         } else {
-            PropertyChanged { property_name: String::from(property_name), left_value: left_value.to_string(), right_value: right_value.to_string() }
+            property_changed(property_name, Some(left_value.to_string()), Some(right_value.to_string()))
+        }
+    }
+
+    fn compare_property_ignore_whitespace<'a, T, P>(&self, property_name: &str, left: T, right: T, accessor: fn(T) -> &'a P) -> PropertyComparison
+        where P: PartialEq, P: Display
+    {
+        let left_value = accessor(left);
+        let right_value = accessor(right);
+
+        if left_value.to_string().as_str().eq_ignore_whitespace(right_value.to_string().as_str()) {
+            PropertyUnchanged { property_name: String::from(property_name), value: Some(left_value.to_string()) }
+        } else {
+            property_changed(property_name, Some(left_value.to_string()), Some(right_value.to_string()))
+        }
+    }
+
+    fn compare_property_normalize_sql<'a, T, P>(&self, property_name: &str, left: T, right: T, accessor: fn(T) -> &'a P) -> PropertyComparison
+        where P: PartialEq, P: Display
+    {
+        let left_value = accessor(left);
+        let right_value = accessor(right);
+
+        if normalize_sql(&left_value.to_string()) == normalize_sql(&right_value.to_string()) {
+            PropertyUnchanged { property_name: String::from(property_name), value: Some(left_value.to_string()) }
+        } else {
+            property_changed(property_name, Some(left_value.to_string()), Some(right_value.to_string()))
         }
     }
 
@@ -768,7 +1436,7 @@ impl Comparer {
         let left_value = accessor(left);
         let right_value = accessor(right);
         
-        self.compare_option_property_impl(property_name, &left_value, &right_value, &|l: &P, r: &P| l == r)
+        self.compare_option_property_impl(property_name, left_value, right_value, &|l: &P, r: &P| l == r)
     }
     
     fn compare_option_property_ignore_whitespace<'a, T, P>(&self, property_name: &str, left: T, right: T, accessor: fn(T) -> &'a Option<P>) -> PropertyComparison
@@ -780,29 +1448,46 @@ impl Comparer {
         self.compare_option_property_impl(property_name, left_value, right_value, &|l: &P, r: &P| l.to_string().as_str().eq_ignore_whitespace(r.to_string().as_str()))
     }
 
+    fn compare_option_property_normalize_sql<'a, T, P>(&self, property_name: &str, left: T, right: T, accessor: fn(T) -> &'a Option<P>) -> PropertyComparison
+        where P: PartialEq, P: Display
+    {
+        let left_value = accessor(left);
+        let right_value = accessor(right);
+
+        self.compare_option_property_impl(property_name, left_value, right_value, &|l: &P, r: &P| normalize_sql(&l.to_string()) == normalize_sql(&r.to_string()))
+    }
+
     fn compare_option_property_impl<T>(&self, property_name: &str, left_value: &Option<T>, right_value: &Option<T>, compare: &dyn Fn(&T, &T) -> bool) -> PropertyComparison
         where T: PartialEq, T: Display
     {
         if left_value.is_none() && right_value.is_none() {
-            return PropertyUnchanged { property_name: String::from(property_name), value: String::from("<none>") }
+            return PropertyUnchanged { property_name: String::from(property_name), value: None }
         }
 
         if left_value.is_none() || right_value.is_none() {
-            return PropertyChanged { property_name: String::from(property_name), left_value: left_value.as_ref().map_or(String::from("<none>"), |v| v.to_string()), right_value: right_value.as_ref().map_or(String::from("<none>"), |v| v.to_string()) };
+            return property_changed(property_name, left_value.as_ref().map(|v| v.to_string()), right_value.as_ref().map(|v| v.to_string()));
         }
 
         let left = left_value.as_ref().unwrap();
         let right = right_value.as_ref().unwrap();
 
         if compare(left, right) {
-            PropertyUnchanged { property_name: String::from(property_name), value: left.to_string() }
+            PropertyUnchanged { property_name: String::from(property_name), value: Some(left.to_string()) }
         } else {
-            PropertyChanged { property_name: String::from(property_name), left_value: left.to_string(), right_value: right.to_string() }
+            property_changed(property_name, Some(left.to_string()), Some(right.to_string()))
         }
     }
     
 }
 
+fn property_changed(property_name: &str, left_value: Option<String>, right_value: Option<String>) -> PropertyComparison {
+    let left_text = left_value.as_deref().unwrap_or_default();
+    let right_text = right_value.as_deref().unwrap_or_default();
+    let diff = (left_text.contains('\n') || right_text.contains('\n')).then(|| unified_diff(left_text, right_text));
+
+    PropertyChanged { property_name: String::from(property_name), left_value, right_value, diff }
+}
+
 fn routine_signature(routine: &Routine, all_parameters: &Vec<&RoutineParameter>) -> String {
     let parameters : Vec<&RoutineParameter> = all_parameters.iter().filter(|p| p.specific_catalog == routine.specific_catalog && p.specific_schema == routine.specific_schema && p.specific_name == routine.specific_name).cloned().collect();
     
@@ -834,3 +1519,37 @@ fn routine_signature(routine: &Routine, all_parameters: &Vec<&RoutineParameter>)
     
     signature
 }
+
+fn is_partition_of(partition: &Table, parent: &Table) -> bool {
+    partition.partition_parent_schema.as_ref() == Some(&parent.table_schema) && partition.partition_parent_name.as_ref() == Some(&parent.table_name)
+}
+
+fn partition_tree<'a>(parent: &Table, partitions: &[&'a Table]) -> Vec<&'a Table> {
+    let mut children: Vec<&Table> = partitions.iter().filter(|p| is_partition_of(p, parent)).cloned().collect();
+    children.sort_unstable_by_key(|p| &p.table_name);
+
+    children.into_iter()
+        .flat_map(|c| std::iter::once(c).chain(partition_tree(c, partitions)))
+        .collect()
+}
+
+fn partition_depth(table: &Table, tables: &[&Table]) -> usize {
+    match tables.iter().find(|t| is_partition_of(table, t)) {
+        Some(parent) => 1 + partition_depth(parent, tables),
+        None => 0,
+    }
+}
+
+fn remove_extension_members(catalog: &mut Catalog) {
+    let members: HashSet<(String, String, String)> = catalog.extension_members.iter()
+        .map(|m| (m.object_schema.clone(), m.object_kind.clone(), m.object_name.clone()))
+        .collect();
+    let is_member = |schema: &str, kind: &str, name: &str| members.contains(&(String::from(schema), String::from(kind), String::from(name)));
+
+    catalog.routines.retain(|r| !is_member(&r.routine_schema, "ROUTINE", &r.specific_name));
+    catalog.tables.retain(|t| !is_member(&t.table_schema, "RELATION", &t.table_name));
+    catalog.views.retain(|v| !is_member(&v.table_schema, "RELATION", &v.table_name));
+    catalog.materialized_views.retain(|v| !is_member(&v.view_schema, "RELATION", &v.view_name));
+    catalog.sequences.retain(|s| !is_member(&s.sequence_schema, "RELATION", &s.sequence_name));
+    catalog.user_types.retain(|t| !is_member(&t.type_schema, "TYPE", &t.type_name));
+}
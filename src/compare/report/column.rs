@@ -1,20 +1,26 @@
+use serde::Serialize;
 use crate::compare::report::privilege::{PrivilegeComparison};
 use crate::compare::report::property::{PropertyComparison};
 use crate::compare::report::{HasChanges, Report};
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
 pub enum ColumnComparison {
-    ColumnAdded { column_name: String },
+    #[serde(rename = "added")]
+    ColumnAdded { column_name: String, definition: String },
+    #[serde(rename = "removed")]
     ColumnRemoved { column_name: String },
+    #[serde(rename = "maintained")]
     ColumnMaintained { column_name: String, properties: Report<PropertyComparison>, privileges: Report<PrivilegeComparison> }
 }
 
 impl HasChanges for ColumnComparison {
-    fn has_changes(&self) -> bool {
+    fn change_count(&self) -> usize {
         match self {
-            ColumnComparison::ColumnAdded { .. } | ColumnComparison::ColumnRemoved { .. } => true,
+            ColumnComparison::ColumnAdded { .. } | ColumnComparison::ColumnRemoved { .. } => 1,
             ColumnComparison::ColumnMaintained { column_name: _column_name, properties, privileges } =>
-                properties.has_changes() |
-                privileges.has_changes(),
+                properties.change_count() +
+                privileges.change_count(),
         }
     }
 }
@@ -0,0 +1,17 @@
+use serde::Serialize;
+use crate::compare::report::{HasChanges, Report};
+use crate::compare::report::extension::ExtensionComparison;
+use crate::compare::report::schema::SchemaComparison;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DatabaseComparison {
+    pub extensions: Report<ExtensionComparison>,
+    pub schemas: Report<SchemaComparison>,
+}
+
+impl HasChanges for DatabaseComparison {
+    fn change_count(&self) -> usize {
+        self.extensions.change_count() +
+            self.schemas.change_count()
+    }
+}
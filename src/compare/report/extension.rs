@@ -0,0 +1,24 @@
+use serde::Serialize;
+use crate::compare::report::property::PropertyComparison;
+use crate::compare::report::{HasChanges, Report};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
+pub enum ExtensionComparison {
+    #[serde(rename = "added")]
+    ExtensionAdded { extension_name: String, definition: String },
+    #[serde(rename = "removed")]
+    ExtensionRemoved { extension_name: String },
+    #[serde(rename = "maintained")]
+    ExtensionMaintained { extension_name: String, properties: Report<PropertyComparison> },
+}
+
+impl HasChanges for ExtensionComparison {
+    fn change_count(&self) -> usize {
+        match self {
+            ExtensionComparison::ExtensionAdded { .. } | ExtensionComparison::ExtensionRemoved { .. } => 1,
+            ExtensionComparison::ExtensionMaintained { extension_name: _extension_name, properties } =>
+                properties.change_count(),
+        }
+    }
+}
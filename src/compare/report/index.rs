@@ -1,17 +1,23 @@
+use serde::Serialize;
 use crate::compare::report::property::PropertyComparison;
 use crate::compare::report::{HasChanges, Report};
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
 pub enum IndexComparison {
-    IndexAdded { index_name: String },
+    #[serde(rename = "added")]
+    IndexAdded { index_name: String, definition: String },
+    #[serde(rename = "removed")]
     IndexRemoved { index_name: String },
+    #[serde(rename = "maintained")]
     IndexMaintained { index_name: String, properties: Report<PropertyComparison> }
 }
 
 impl HasChanges for IndexComparison {
-    fn has_changes(&self) -> bool {
+    fn change_count(&self) -> usize {
         match self {
-            IndexComparison::IndexAdded { .. } | IndexComparison::IndexRemoved { .. } => true,
-            IndexComparison::IndexMaintained { index_name: _index_name, properties } => properties.has_changes(),
+            IndexComparison::IndexAdded { .. } | IndexComparison::IndexRemoved { .. } => 1,
+            IndexComparison::IndexMaintained { index_name: _index_name, properties } => properties.change_count(),
         }
     }
 }
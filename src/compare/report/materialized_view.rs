@@ -0,0 +1,28 @@
+use serde::Serialize;
+use crate::compare::report::index::IndexComparison;
+use crate::compare::report::privilege::PrivilegeComparison;
+use crate::compare::report::property::PropertyComparison;
+use crate::compare::report::{HasChanges, Report};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
+pub enum MaterializedViewComparison {
+    #[serde(rename = "added")]
+    MaterializedViewAdded { view_name: String, definition: String },
+    #[serde(rename = "removed")]
+    MaterializedViewRemoved { view_name: String },
+    #[serde(rename = "maintained")]
+    MaterializedViewMaintained { view_name: String, definition: String, indices: Report<IndexComparison>, privileges: Report<PrivilegeComparison>, properties: Report<PropertyComparison> },
+}
+
+impl HasChanges for MaterializedViewComparison {
+    fn change_count(&self) -> usize {
+        match self {
+            MaterializedViewComparison::MaterializedViewAdded { .. } | MaterializedViewComparison::MaterializedViewRemoved { .. } => 1,
+            MaterializedViewComparison::MaterializedViewMaintained { view_name: _view_name, definition: _definition, indices, privileges, properties } =>
+                indices.change_count() +
+                privileges.change_count() +
+                properties.change_count(),
+        }
+    }
+}
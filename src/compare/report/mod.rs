@@ -1,25 +1,41 @@
 pub mod column;
+pub mod database;
+pub mod extension;
 pub mod index;
+pub mod policy;
+pub mod materialized_view;
+pub mod partition;
 pub mod property;
 pub mod privilege;
 pub mod routine;
 pub mod schema;
 pub mod sequence;
+pub mod summary;
 pub mod table;
 pub mod table_constraint;
 pub mod table_trigger;
+pub mod user_type;
+pub mod user_type_attribute;
 pub mod view;
 
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(transparent)]
 pub struct Report<T: HasChanges> {
     pub entries: Vec<T>,
 }
 
-impl<T: HasChanges> Report<T> {
-    fn has_changes(&self) -> bool {
-        self.entries.iter().any(|e| e.has_changes())
+impl<T: HasChanges> HasChanges for Report<T> {
+    fn change_count(&self) -> usize {
+        self.entries.iter().map(|e| e.change_count()).sum()
     }
 }
 
 pub trait HasChanges {
-    fn has_changes(&self) -> bool;
+    fn change_count(&self) -> usize;
+
+    fn has_changes(&self) -> bool {
+        self.change_count() > 0
+    }
 }
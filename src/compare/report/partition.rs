@@ -0,0 +1,24 @@
+use serde::Serialize;
+use crate::compare::report::property::PropertyComparison;
+use crate::compare::report::{HasChanges, Report};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
+pub enum PartitionComparison {
+    #[serde(rename = "added")]
+    PartitionAdded { partition_name: String, definition: String },
+    #[serde(rename = "removed")]
+    PartitionRemoved { partition_name: String },
+    #[serde(rename = "maintained")]
+    PartitionMaintained { partition_name: String, properties: Report<PropertyComparison> },
+}
+
+impl HasChanges for PartitionComparison {
+    fn change_count(&self) -> usize {
+        match self {
+            PartitionComparison::PartitionAdded { .. } | PartitionComparison::PartitionRemoved { .. } => 1,
+            PartitionComparison::PartitionMaintained { partition_name: _partition_name, properties } =>
+                properties.change_count(),
+        }
+    }
+}
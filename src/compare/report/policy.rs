@@ -0,0 +1,24 @@
+use serde::Serialize;
+use crate::compare::report::property::PropertyComparison;
+use crate::compare::report::{HasChanges, Report};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
+pub enum PolicyComparison {
+    #[serde(rename = "added")]
+    PolicyAdded { policy_name: String, definition: String },
+    #[serde(rename = "removed")]
+    PolicyRemoved { policy_name: String },
+    #[serde(rename = "maintained")]
+    PolicyMaintained { policy_name: String, definition: String, properties: Report<PropertyComparison> },
+}
+
+impl HasChanges for PolicyComparison {
+    fn change_count(&self) -> usize {
+        match self {
+            PolicyComparison::PolicyAdded { .. } | PolicyComparison::PolicyRemoved { .. } => 1,
+            PolicyComparison::PolicyMaintained { policy_name: _policy_name, definition: _definition, properties } =>
+                properties.change_count(),
+        }
+    }
+}
@@ -1,16 +1,22 @@
+use serde::Serialize;
 use crate::compare::report::HasChanges;
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
 pub enum PrivilegeComparison {
+    #[serde(rename = "added")]
     PrivilegeAdded { privilege_name: String, grantor: String, grantee: String },
+    #[serde(rename = "removed")]
     PrivilegeRemoved { privilege_name: String, grantor: String, grantee: String },
+    #[serde(rename = "maintained")]
     PrivilegeMaintained { privilege_name: String, grantor: String, grantee: String }
 }
 
 impl HasChanges for PrivilegeComparison {
-    fn has_changes(&self) -> bool {
+    fn change_count(&self) -> usize {
         match self {
-            PrivilegeComparison::PrivilegeAdded { .. } | PrivilegeComparison::PrivilegeRemoved { .. } => true,
-            PrivilegeComparison::PrivilegeMaintained { .. } => false,
+            PrivilegeComparison::PrivilegeAdded { .. } | PrivilegeComparison::PrivilegeRemoved { .. } => 1,
+            PrivilegeComparison::PrivilegeMaintained { .. } => 0,
         }
     }
 }
@@ -1,15 +1,28 @@
+use serde::Serialize;
 use crate::compare::report::HasChanges;
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
 pub enum PropertyComparison {
-    PropertyChanged { property_name: String, left_value: String, right_value: String },
-    PropertyUnchanged { property_name: String, value: String },
+    #[serde(rename = "changed")]
+    PropertyChanged {
+        property_name: String,
+        // `None` where the property has no value
+        left_value: Option<String>,
+        right_value: Option<String>,
+        // unified diff of multi-line values
+        #[serde(skip_serializing_if = "Option::is_none")]
+        diff: Option<String>,
+    },
+    #[serde(rename = "unchanged")]
+    PropertyUnchanged { property_name: String, value: Option<String> },
 }
 
 impl HasChanges for PropertyComparison {
-    fn has_changes(&self) -> bool {
+    fn change_count(&self) -> usize {
         match self {
-            PropertyComparison::PropertyChanged { .. } => true,
-            PropertyComparison::PropertyUnchanged { .. } => false,
+            PropertyComparison::PropertyChanged { .. } => 1,
+            PropertyComparison::PropertyUnchanged { .. } => 0,
         }
     }
 }
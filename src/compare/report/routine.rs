@@ -1,20 +1,26 @@
+use serde::Serialize;
 use crate::compare::report::property::{PropertyComparison};
 use crate::compare::report::{HasChanges, Report};
 use crate::compare::report::privilege::PrivilegeComparison;
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
 pub enum RoutineComparison {
-    RoutineAdded { routine_signature: String },
+    #[serde(rename = "added")]
+    RoutineAdded { routine_signature: String, definition: Option<String> },
+    #[serde(rename = "removed")]
     RoutineRemoved { routine_signature: String },
-    RoutineMaintained { routine_signature: String, properties: Report<PropertyComparison>, privileges: Report<PrivilegeComparison> },
+    #[serde(rename = "maintained")]
+    RoutineMaintained { routine_signature: String, definition: Option<String>, properties: Report<PropertyComparison>, privileges: Report<PrivilegeComparison> },
 }
 
 impl HasChanges for RoutineComparison {
-    fn has_changes(&self) -> bool {
+    fn change_count(&self) -> usize {
         match self {
-            RoutineComparison::RoutineAdded { .. } | RoutineComparison::RoutineRemoved { .. } => true,
-            RoutineComparison::RoutineMaintained { routine_signature: _routine_signature, properties, privileges } =>
-                properties.has_changes() ||
-                privileges.has_changes(),
+            RoutineComparison::RoutineAdded { .. } | RoutineComparison::RoutineRemoved { .. } => 1,
+            RoutineComparison::RoutineMaintained { properties, privileges, .. } =>
+                properties.change_count() +
+                privileges.change_count(),
         }
     }
 }
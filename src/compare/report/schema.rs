@@ -1,27 +1,38 @@
+use serde::Serialize;
 use crate::compare::report::{HasChanges, Report};
+use crate::compare::report::materialized_view::MaterializedViewComparison;
 use crate::compare::report::property::PropertyComparison;
 use crate::compare::report::routine::RoutineComparison;
 use crate::compare::report::sequence::SequenceComparison;
 use crate::compare::report::table::TableComparison;
+use crate::compare::report::user_type::UserTypeComparison;
 use crate::compare::report::view::ViewComparison;
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
 pub enum SchemaComparison {
+    #[serde(rename = "added")]
     SchemaAdded { schema_name: String },
+    #[serde(rename = "removed")]
     SchemaRemoved { schema_name: String },
+    #[serde(rename = "missing")]
     SchemaMissing { schema_name: String },
-    SchemaMaintained { schema_name: String, properties: Report<PropertyComparison>, routines: Report<RoutineComparison>, sequences: Report<SequenceComparison>, tables: Report<TableComparison>, views: Report<ViewComparison> },
+    #[serde(rename = "maintained")]
+    SchemaMaintained { schema_name: String, properties: Report<PropertyComparison>, materialized_views: Report<MaterializedViewComparison>, routines: Report<RoutineComparison>, sequences: Report<SequenceComparison>, tables: Report<TableComparison>, types: Report<UserTypeComparison>, views: Report<ViewComparison> },
 }
 
 impl HasChanges for SchemaComparison {
-    fn has_changes(&self) -> bool {
+    fn change_count(&self) -> usize {
         match self {
-            SchemaComparison::SchemaAdded { .. } | SchemaComparison::SchemaRemoved { .. } | SchemaComparison::SchemaMissing { .. } => true,
-            SchemaComparison::SchemaMaintained { schema_name: _schema_name, properties, routines, sequences, tables, views } =>
-                properties.has_changes() ||
-                routines.has_changes() ||
-                sequences.has_changes() ||
-                tables.has_changes() ||
-                views.has_changes(),
+            SchemaComparison::SchemaAdded { .. } | SchemaComparison::SchemaRemoved { .. } | SchemaComparison::SchemaMissing { .. } => 1,
+            SchemaComparison::SchemaMaintained { schema_name: _schema_name, properties, materialized_views, routines, sequences, tables, types, views } =>
+                properties.change_count() +
+                materialized_views.change_count() +
+                routines.change_count() +
+                sequences.change_count() +
+                tables.change_count() +
+                types.change_count() +
+                views.change_count(),
         }
     }
 }
@@ -1,18 +1,24 @@
+use serde::Serialize;
 use crate::compare::report::property::{PropertyComparison};
 use crate::compare::report::{HasChanges, Report};
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
 pub enum SequenceComparison {
-    SequenceAdded { sequence_name: String },
+    #[serde(rename = "added")]
+    SequenceAdded { sequence_name: String, definition: String },
+    #[serde(rename = "removed")]
     SequenceRemoved { sequence_name: String },
+    #[serde(rename = "maintained")]
     SequenceMaintained { sequence_name: String, properties: Report<PropertyComparison> },
 }
 
 impl HasChanges for SequenceComparison {
-    fn has_changes(&self) -> bool {
+    fn change_count(&self) -> usize {
         match self {
-            SequenceComparison::SequenceAdded { .. } | SequenceComparison::SequenceRemoved { .. } => true,
+            SequenceComparison::SequenceAdded { .. } | SequenceComparison::SequenceRemoved { .. } => 1,
             SequenceComparison::SequenceMaintained { sequence_name: _sequence_name, properties } =>
-                properties.has_changes(),
+                properties.change_count(),
         }
     }
 }
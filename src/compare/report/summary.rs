@@ -0,0 +1,282 @@
+use serde::Serialize;
+use crate::compare::report::{HasChanges, Report};
+use crate::compare::report::column::ColumnComparison;
+use crate::compare::report::database::DatabaseComparison;
+use crate::compare::report::extension::ExtensionComparison;
+use crate::compare::report::index::IndexComparison;
+use crate::compare::report::materialized_view::MaterializedViewComparison;
+use crate::compare::report::partition::PartitionComparison;
+use crate::compare::report::policy::PolicyComparison;
+use crate::compare::report::privilege::PrivilegeComparison;
+use crate::compare::report::routine::RoutineComparison;
+use crate::compare::report::schema::SchemaComparison;
+use crate::compare::report::sequence::SequenceComparison;
+use crate::compare::report::table::TableComparison;
+use crate::compare::report::table_constraint::TableConstraintComparison;
+use crate::compare::report::table_trigger::TableTriggerComparison;
+use crate::compare::report::user_type::UserTypeComparison;
+use crate::compare::report::user_type_attribute::UserTypeAttributeComparison;
+use crate::compare::report::view::ViewComparison;
+
+/// The number of objects of one kind that were added, removed or changed.
+///
+/// An object counts as changed when its own properties differ; changes to the objects it contains are counted under
+/// their own kind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct ChangeTally {
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+}
+
+impl ChangeTally {
+    pub fn total(&self) -> usize {
+        self.added + self.removed + self.changed
+    }
+
+    fn count_changed(&mut self, properties: &impl HasChanges) {
+        if properties.has_changes() {
+            self.changed += 1;
+        }
+    }
+}
+
+/// Change tallies per kind of object across a [`DatabaseComparison`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Summary {
+    pub extensions: ChangeTally,
+    pub schemas: ChangeTally,
+    pub tables: ChangeTally,
+    pub columns: ChangeTally,
+    pub constraints: ChangeTally,
+    pub indices: ChangeTally,
+    pub triggers: ChangeTally,
+    pub partitions: ChangeTally,
+    pub policies: ChangeTally,
+    pub privileges: ChangeTally,
+    pub routines: ChangeTally,
+    pub sequences: ChangeTally,
+    pub views: ChangeTally,
+    pub materialized_views: ChangeTally,
+    pub types: ChangeTally,
+    pub attributes: ChangeTally,
+}
+
+impl Summary {
+    pub fn new(report: &DatabaseComparison) -> Summary {
+        let mut summary = Summary::default();
+
+        for extension in &report.extensions.entries {
+            match extension {
+                ExtensionComparison::ExtensionAdded { .. } => summary.extensions.added += 1,
+                ExtensionComparison::ExtensionRemoved { .. } => summary.extensions.removed += 1,
+                ExtensionComparison::ExtensionMaintained { properties, .. } => summary.extensions.count_changed(properties),
+            }
+        }
+
+        for schema in &report.schemas.entries {
+            match schema {
+                SchemaComparison::SchemaAdded { .. } => summary.schemas.added += 1,
+                SchemaComparison::SchemaRemoved { .. } => summary.schemas.removed += 1,
+                SchemaComparison::SchemaMissing { .. } => {},
+                SchemaComparison::SchemaMaintained { properties, materialized_views, routines, sequences, tables, types, views, .. } => {
+                    summary.schemas.count_changed(properties);
+                    summary.add_materialized_views(materialized_views);
+                    summary.add_routines(routines);
+                    summary.add_sequences(sequences);
+                    summary.add_tables(tables);
+                    summary.add_types(types);
+                    summary.add_views(views);
+                },
+            }
+        }
+
+        summary
+    }
+
+    /// The tallies in report order, each with a plural name for display.
+    pub fn tallies(&self) -> Vec<(&'static str, &ChangeTally)> {
+        vec![
+            ("extensions", &self.extensions),
+            ("schemas", &self.schemas),
+            ("tables", &self.tables),
+            ("columns", &self.columns),
+            ("constraints", &self.constraints),
+            ("indices", &self.indices),
+            ("triggers", &self.triggers),
+            ("partitions", &self.partitions),
+            ("policies", &self.policies),
+            ("privileges", &self.privileges),
+            ("routines", &self.routines),
+            ("sequences", &self.sequences),
+            ("views", &self.views),
+            ("materialized views", &self.materialized_views),
+            ("types", &self.types),
+            ("attributes", &self.attributes),
+        ]
+    }
+
+    fn add_materialized_views(&mut self, report: &Report<MaterializedViewComparison>) {
+        for view in &report.entries {
+            match view {
+                MaterializedViewComparison::MaterializedViewAdded { .. } => self.materialized_views.added += 1,
+                MaterializedViewComparison::MaterializedViewRemoved { .. } => self.materialized_views.removed += 1,
+                MaterializedViewComparison::MaterializedViewMaintained { indices, privileges, properties, .. } => {
+                    self.materialized_views.count_changed(properties);
+                    self.add_indices(indices);
+                    self.add_privileges(privileges);
+                },
+            }
+        }
+    }
+
+    fn add_routines(&mut self, report: &Report<RoutineComparison>) {
+        for routine in &report.entries {
+            match routine {
+                RoutineComparison::RoutineAdded { .. } => self.routines.added += 1,
+                RoutineComparison::RoutineRemoved { .. } => self.routines.removed += 1,
+                RoutineComparison::RoutineMaintained { properties, privileges, .. } => {
+                    self.routines.count_changed(properties);
+                    self.add_privileges(privileges);
+                },
+            }
+        }
+    }
+
+    fn add_sequences(&mut self, report: &Report<SequenceComparison>) {
+        for sequence in &report.entries {
+            match sequence {
+                SequenceComparison::SequenceAdded { .. } => self.sequences.added += 1,
+                SequenceComparison::SequenceRemoved { .. } => self.sequences.removed += 1,
+                SequenceComparison::SequenceMaintained { properties, .. } => self.sequences.count_changed(properties),
+            }
+        }
+    }
+
+    fn add_tables(&mut self, report: &Report<TableComparison>) {
+        for table in &report.entries {
+            match table {
+                TableComparison::TableAdded { .. } => self.tables.added += 1,
+                TableComparison::TableRemoved { .. } => self.tables.removed += 1,
+                TableComparison::TableMaintained { columns, constraints, indices, partitions, policies, privileges, properties, triggers, .. } => {
+                    self.tables.count_changed(properties);
+                    self.add_columns(columns);
+                    self.add_constraints(constraints);
+                    self.add_indices(indices);
+                    self.add_partitions(partitions);
+                    self.add_policies(policies);
+                    self.add_privileges(privileges);
+                    self.add_triggers(triggers);
+                },
+            }
+        }
+    }
+
+    fn add_columns(&mut self, report: &Report<ColumnComparison>) {
+        for column in &report.entries {
+            match column {
+                ColumnComparison::ColumnAdded { .. } => self.columns.added += 1,
+                ColumnComparison::ColumnRemoved { .. } => self.columns.removed += 1,
+                ColumnComparison::ColumnMaintained { properties, privileges, .. } => {
+                    self.columns.count_changed(properties);
+                    self.add_privileges(privileges);
+                },
+            }
+        }
+    }
+
+    fn add_constraints(&mut self, report: &Report<TableConstraintComparison>) {
+        for constraint in &report.entries {
+            match constraint {
+                TableConstraintComparison::ConstraintAdded { .. } => self.constraints.added += 1,
+                TableConstraintComparison::ConstraintRemoved { .. } => self.constraints.removed += 1,
+                TableConstraintComparison::ConstraintMaintained { properties, .. } => self.constraints.count_changed(properties),
+            }
+        }
+    }
+
+    fn add_indices(&mut self, report: &Report<IndexComparison>) {
+        for index in &report.entries {
+            match index {
+                IndexComparison::IndexAdded { .. } => self.indices.added += 1,
+                IndexComparison::IndexRemoved { .. } => self.indices.removed += 1,
+                IndexComparison::IndexMaintained { properties, .. } => self.indices.count_changed(properties),
+            }
+        }
+    }
+
+    fn add_partitions(&mut self, report: &Report<PartitionComparison>) {
+        for partition in &report.entries {
+            match partition {
+                PartitionComparison::PartitionAdded { .. } => self.partitions.added += 1,
+                PartitionComparison::PartitionRemoved { .. } => self.partitions.removed += 1,
+                PartitionComparison::PartitionMaintained { properties, .. } => self.partitions.count_changed(properties),
+            }
+        }
+    }
+
+    fn add_policies(&mut self, report: &Report<PolicyComparison>) {
+        for policy in &report.entries {
+            match policy {
+                PolicyComparison::PolicyAdded { .. } => self.policies.added += 1,
+                PolicyComparison::PolicyRemoved { .. } => self.policies.removed += 1,
+                PolicyComparison::PolicyMaintained { properties, .. } => self.policies.count_changed(properties),
+            }
+        }
+    }
+
+    fn add_privileges(&mut self, report: &Report<PrivilegeComparison>) {
+        for privilege in &report.entries {
+            match privilege {
+                PrivilegeComparison::PrivilegeAdded { .. } => self.privileges.added += 1,
+                PrivilegeComparison::PrivilegeRemoved { .. } => self.privileges.removed += 1,
+                PrivilegeComparison::PrivilegeMaintained { .. } => {},
+            }
+        }
+    }
+
+    fn add_triggers(&mut self, report: &Report<TableTriggerComparison>) {
+        for trigger in &report.entries {
+            match trigger {
+                TableTriggerComparison::TriggerAdded { .. } => self.triggers.added += 1,
+                TableTriggerComparison::TriggerRemoved { .. } => self.triggers.removed += 1,
+                TableTriggerComparison::TriggerMaintained { properties, .. } => self.triggers.count_changed(properties),
+            }
+        }
+    }
+
+    fn add_types(&mut self, report: &Report<UserTypeComparison>) {
+        for user_type in &report.entries {
+            match user_type {
+                UserTypeComparison::UserTypeAdded { .. } => self.types.added += 1,
+                UserTypeComparison::UserTypeRemoved { .. } => self.types.removed += 1,
+                UserTypeComparison::UserTypeMaintained { attributes, constraints, privileges, properties, .. } => {
+                    self.types.count_changed(properties);
+                    self.add_attributes(attributes);
+                    self.add_constraints(constraints);
+                    self.add_privileges(privileges);
+                },
+            }
+        }
+    }
+
+    fn add_attributes(&mut self, report: &Report<UserTypeAttributeComparison>) {
+        for attribute in &report.entries {
+            match attribute {
+                UserTypeAttributeComparison::AttributeAdded { .. } => self.attributes.added += 1,
+                UserTypeAttributeComparison::AttributeRemoved { .. } => self.attributes.removed += 1,
+                UserTypeAttributeComparison::AttributeMaintained { properties, .. } => self.attributes.count_changed(properties),
+            }
+        }
+    }
+
+    fn add_views(&mut self, report: &Report<ViewComparison>) {
+        for view in &report.entries {
+            match view {
+                ViewComparison::ViewAdded { .. } => self.views.added += 1,
+                ViewComparison::ViewRemoved { .. } => self.views.removed += 1,
+                ViewComparison::ViewMaintained { properties, .. } => self.views.count_changed(properties),
+            }
+        }
+    }
+}
@@ -1,19 +1,29 @@
+use serde::Serialize;
 use crate::compare::report::{HasChanges, Report};
 use crate::compare::report::privilege::PrivilegeComparison;
 use crate::compare::report::property::PropertyComparison;
 use crate::compare::report::column::ColumnComparison;
 use crate::compare::report::index::IndexComparison;
+use crate::compare::report::partition::PartitionComparison;
+use crate::compare::report::policy::PolicyComparison;
 use crate::compare::report::table_constraint::TableConstraintComparison;
 use crate::compare::report::table_trigger::TableTriggerComparison;
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
 pub enum TableComparison {
-    TableAdded { table_name: String },
-    TableRemoved { table_name: String },
+    #[serde(rename = "added")]
+    TableAdded { table_name: String, table_type: String, definition: String },
+    #[serde(rename = "removed")]
+    TableRemoved { table_name: String, table_type: String },
+    #[serde(rename = "maintained")]
     TableMaintained {
         table_name: String,
         columns: Report<ColumnComparison>,
         constraints: Report<TableConstraintComparison>,
         indices: Report<IndexComparison>,
+        partitions: Report<PartitionComparison>,
+        policies: Report<PolicyComparison>,
         privileges: Report<PrivilegeComparison>,
         properties: Report<PropertyComparison>,
         triggers: Report<TableTriggerComparison>
@@ -21,16 +31,18 @@ pub enum TableComparison {
 }
 
 impl HasChanges for TableComparison {
-    fn has_changes(&self) -> bool {
+    fn change_count(&self) -> usize {
         match self {
-            TableComparison::TableAdded { .. } | TableComparison::TableRemoved { .. } => true,
-            TableComparison::TableMaintained { table_name: _table_name, columns, constraints, indices, privileges, properties, triggers } =>
-                columns.has_changes() ||
-                indices.has_changes() ||
-                privileges.has_changes() ||
-                properties.has_changes() ||
-                constraints.has_changes() ||
-                triggers.has_changes(),
+            TableComparison::TableAdded { .. } | TableComparison::TableRemoved { .. } => 1,
+            TableComparison::TableMaintained { table_name: _table_name, columns, constraints, indices, partitions, policies, privileges, properties, triggers } =>
+                columns.change_count() +
+                indices.change_count() +
+                partitions.change_count() +
+                policies.change_count() +
+                privileges.change_count() +
+                properties.change_count() +
+                constraints.change_count() +
+                triggers.change_count(),
         }
     }
 }
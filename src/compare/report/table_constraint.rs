@@ -1,18 +1,24 @@
+use serde::Serialize;
 use crate::compare::report::property::{PropertyComparison};
 use crate::compare::report::{HasChanges, Report};
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
 pub enum TableConstraintComparison {
-    ConstraintAdded { constraint_name: String },
+    #[serde(rename = "added")]
+    ConstraintAdded { constraint_name: String, definition: Option<String> },
+    #[serde(rename = "removed")]
     ConstraintRemoved { constraint_name: String },
+    #[serde(rename = "maintained")]
     ConstraintMaintained { constraint_name: String, properties: Report<PropertyComparison> },
 }
 
 impl HasChanges for TableConstraintComparison {
-    fn has_changes(&self) -> bool {
+    fn change_count(&self) -> usize {
         match self {
-            TableConstraintComparison::ConstraintAdded { .. } | TableConstraintComparison::ConstraintRemoved { .. } => true,
+            TableConstraintComparison::ConstraintAdded { .. } | TableConstraintComparison::ConstraintRemoved { .. } => 1,
             TableConstraintComparison::ConstraintMaintained { constraint_name: _constraint_name, properties } =>
-                properties.has_changes(),
+                properties.change_count(),
         }
     }
 }
@@ -1,18 +1,24 @@
+use serde::Serialize;
 use crate::compare::report::{HasChanges, Report};
 use crate::compare::report::property::PropertyComparison;
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
 pub enum TableTriggerComparison {
-    TriggerAdded { trigger_name: String, event_manipulation: String },
+    #[serde(rename = "added")]
+    TriggerAdded { trigger_name: String, event_manipulation: String, definition: Option<String> },
+    #[serde(rename = "removed")]
     TriggerRemoved { trigger_name: String, event_manipulation: String },
-    TriggerMaintained { trigger_name: String, event_manipulation: String, properties: Report<PropertyComparison> }
+    #[serde(rename = "maintained")]
+    TriggerMaintained { trigger_name: String, event_manipulation: String, definition: Option<String>, properties: Report<PropertyComparison> }
 }
 
 impl HasChanges for TableTriggerComparison {
-    fn has_changes(&self) -> bool {
+    fn change_count(&self) -> usize {
         match self {
-            TableTriggerComparison::TriggerAdded { .. } | TableTriggerComparison::TriggerRemoved { .. } => true,
-            TableTriggerComparison::TriggerMaintained { trigger_name: _trigger_name, event_manipulation: _event_manipulation, properties } =>
-                properties.has_changes(),
+            TableTriggerComparison::TriggerAdded { .. } | TableTriggerComparison::TriggerRemoved { .. } => 1,
+            TableTriggerComparison::TriggerMaintained { properties, .. } =>
+                properties.change_count(),
         }
     }
 }
@@ -0,0 +1,30 @@
+use serde::Serialize;
+use crate::compare::report::privilege::PrivilegeComparison;
+use crate::compare::report::property::PropertyComparison;
+use crate::compare::report::table_constraint::TableConstraintComparison;
+use crate::compare::report::user_type_attribute::UserTypeAttributeComparison;
+use crate::compare::report::{HasChanges, Report};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
+pub enum UserTypeComparison {
+    #[serde(rename = "added")]
+    UserTypeAdded { type_name: String, type_kind: String, definition: String },
+    #[serde(rename = "removed")]
+    UserTypeRemoved { type_name: String, type_kind: String },
+    #[serde(rename = "maintained")]
+    UserTypeMaintained { type_name: String, attributes: Report<UserTypeAttributeComparison>, constraints: Report<TableConstraintComparison>, privileges: Report<PrivilegeComparison>, properties: Report<PropertyComparison> },
+}
+
+impl HasChanges for UserTypeComparison {
+    fn change_count(&self) -> usize {
+        match self {
+            UserTypeComparison::UserTypeAdded { .. } | UserTypeComparison::UserTypeRemoved { .. } => 1,
+            UserTypeComparison::UserTypeMaintained { type_name: _type_name, attributes, constraints, privileges, properties } =>
+                attributes.change_count() +
+                constraints.change_count() +
+                privileges.change_count() +
+                properties.change_count(),
+        }
+    }
+}
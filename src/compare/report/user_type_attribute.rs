@@ -0,0 +1,24 @@
+use serde::Serialize;
+use crate::compare::report::property::PropertyComparison;
+use crate::compare::report::{HasChanges, Report};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
+pub enum UserTypeAttributeComparison {
+    #[serde(rename = "added")]
+    AttributeAdded { attribute_name: String, definition: String },
+    #[serde(rename = "removed")]
+    AttributeRemoved { attribute_name: String },
+    #[serde(rename = "maintained")]
+    AttributeMaintained { attribute_name: String, properties: Report<PropertyComparison> },
+}
+
+impl HasChanges for UserTypeAttributeComparison {
+    fn change_count(&self) -> usize {
+        match self {
+            UserTypeAttributeComparison::AttributeAdded { .. } | UserTypeAttributeComparison::AttributeRemoved { .. } => 1,
+            UserTypeAttributeComparison::AttributeMaintained { attribute_name: _attribute_name, properties } =>
+                properties.change_count(),
+        }
+    }
+}
@@ -1,15 +1,24 @@
+use serde::Serialize;
 use crate::compare::report::property::{PropertyComparison};
 use crate::compare::report::{HasChanges, Report};
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change")]
 pub enum ViewComparison {
+    #[serde(rename = "added")]
+    ViewAdded { view_name: String, definition: String },
+    #[serde(rename = "removed")]
+    ViewRemoved { view_name: String },
+    #[serde(rename = "maintained")]
     ViewMaintained { view_name: String, properties: Report<PropertyComparison> },
 }
 
 impl HasChanges for ViewComparison {
-    fn has_changes(&self) -> bool {
+    fn change_count(&self) -> usize {
         match self {
+            ViewComparison::ViewAdded { .. } | ViewComparison::ViewRemoved { .. } => 1,
             ViewComparison::ViewMaintained { view_name: _view_name, properties } =>
-                properties.has_changes(),
+                properties.change_count(),
         }
     }
 }
@@ -0,0 +1,561 @@
+use std::collections::{HashMap, HashSet};
+use serde_json::json;
+use crate::compare::{Comparer, Options};
+use crate::compare::filter::{is_known_property_pattern, matches_property_path, ObjectFilter};
+use crate::compare::report::HasChanges;
+use crate::compare::report::column::ColumnComparison::{ColumnAdded, ColumnMaintained};
+use crate::compare::report::extension::ExtensionComparison::{ExtensionAdded, ExtensionMaintained};
+use crate::compare::report::partition::PartitionComparison::{PartitionAdded, PartitionMaintained};
+use crate::compare::report::property::PropertyComparison::{PropertyChanged, PropertyUnchanged};
+use crate::compare::report::schema::SchemaComparison;
+use crate::compare::report::schema::SchemaComparison::{SchemaAdded, SchemaMaintained, SchemaMissing};
+use crate::compare::report::summary::{ChangeTally, Summary};
+use crate::compare::report::table::TableComparison::{TableAdded, TableMaintained, TableRemoved};
+use crate::compare::report::user_type::UserTypeComparison::{UserTypeAdded, UserTypeMaintained, UserTypeRemoved};
+use crate::compare::report::view::ViewComparison::{ViewAdded, ViewRemoved};
+use crate::db::catalog::Catalog;
+use crate::db::column::Column;
+use crate::db::extension::Extension;
+use crate::db::extension_member::ExtensionMember;
+use crate::db::routine::Routine;
+use crate::db::schema::Schema;
+use crate::db::table::Table;
+use crate::db::user_type::UserType;
+use crate::db::view::View;
+
+fn schema(schema_name: &str) -> Schema {
+    serde_json::from_value(json!({
+        "schema_name": schema_name,
+        "schema_owner": "postgres",
+    })).unwrap()
+}
+
+fn table(table_name: &str) -> Table {
+    serde_json::from_value(json!({
+        "table_catalog": "postgres",
+        "table_schema": "test",
+        "table_name": table_name,
+        "table_type": "BASE TABLE",
+        "is_insertable_into": "YES",
+        "is_typed": "NO",
+        "row_security": "NO",
+        "force_row_security": "NO",
+    })).unwrap()
+}
+
+fn column(table_name: &str, column_name: &str, ordinal_position: i32, data_type: &str) -> Column {
+    serde_json::from_value(json!({
+        "table_catalog": "postgres",
+        "table_schema": "test",
+        "table_name": table_name,
+        "column_name": column_name,
+        "ordinal_position": ordinal_position,
+        "is_nullable": "YES",
+        "data_type": data_type,
+        "column_type": data_type,
+        "is_self_referencing": "NO",
+        "is_identity": "NO",
+        "is_generated": "NEVER",
+        "is_updatable": "YES",
+        "is_local": "YES",
+        "inheritance_count": 0,
+    })).unwrap()
+}
+
+fn catalog(tables: Vec<Table>, columns: Vec<Column>) -> Catalog {
+    Catalog {
+        schema_names: vec![String::from("test")],
+        schemas: vec![schema("test")],
+        tables,
+        columns,
+        ..Catalog::default()
+    }
+}
+
+// a catalog of another schema than `test`, such as a template to be compared with it
+fn catalog_in(schema_name: &str, tables: Vec<Table>, columns: Vec<Column>, routines: Vec<Routine>) -> Catalog {
+    Catalog {
+        schema_names: vec![String::from(schema_name)],
+        schemas: vec![schema(schema_name)],
+        tables: tables.into_iter().map(|t| Table { table_schema: String::from(schema_name), ..t }).collect(),
+        columns: columns.into_iter().map(|c| Column { table_schema: String::from(schema_name), ..c }).collect(),
+        routines: routines.into_iter()
+            .map(|r| Routine { specific_schema: String::from(schema_name), routine_schema: String::from(schema_name), ..r })
+            .collect(),
+        ..Catalog::default()
+    }
+}
+
+fn routine(routine_name: &str) -> Routine {
+    serde_json::from_value(json!({
+        "specific_catalog": "postgres",
+        "specific_schema": "test",
+        "specific_name": format!("{}_1", routine_name),
+        "routine_catalog": "postgres",
+        "routine_schema": "test",
+        "routine_name": routine_name,
+        "routine_body": "SQL",
+        "external_language": "SQL",
+        "parameter_style": "GENERAL",
+        "is_deterministic": "NO",
+        "sql_data_access": "READS",
+        "schema_level_routine": "YES",
+        "security_type": "INVOKER",
+        "is_udt_dependent": "NO",
+    })).unwrap()
+}
+
+fn view(view_name: &str) -> (Table, View) {
+    let table = serde_json::from_value(json!({
+        "table_catalog": "postgres",
+        "table_schema": "test",
+        "table_name": view_name,
+        "table_type": "VIEW",
+        "is_insertable_into": "NO",
+        "is_typed": "NO",
+        "row_security": "NO",
+        "force_row_security": "NO",
+    })).unwrap();
+
+    let view = serde_json::from_value(json!({
+        "table_catalog": "postgres",
+        "table_schema": "test",
+        "table_name": view_name,
+        "view_definition": " SELECT 1;",
+        "check_option": "NONE",
+        "is_updatable": "NO",
+        "is_insertable_into": "NO",
+        "is_trigger_updatable": "NO",
+        "is_trigger_deletable": "NO",
+        "is_trigger_insertable_into": "NO",
+    })).unwrap();
+
+    (table, view)
+}
+
+fn partition(table_name: &str, parent_name: &str, partition_bound: &str) -> Table {
+    Table {
+        partition_parent_schema: Some(String::from("test")),
+        partition_parent_name: Some(String::from(parent_name)),
+        partition_bound: Some(String::from(partition_bound)),
+        ..table(table_name)
+    }
+}
+
+async fn compare(left: Catalog, right: Catalog, options: Options) -> Vec<SchemaComparison> {
+    let comparer = Comparer::new(Box::new(left), Box::new(right), options);
+
+    comparer.compare(vec![String::from("test")]).await.unwrap().schemas.entries
+}
+
+#[tokio::test]
+async fn test_identical() {
+    let catalog = catalog(vec![table("a")], vec![column("a", "id", 1, "integer")]);
+
+    let entries = compare(catalog.clone(), catalog, Options::default()).await;
+
+    assert!(matches!(&entries[..], [SchemaMaintained { .. }]));
+    assert!(!entries[0].has_changes());
+}
+
+#[tokio::test]
+async fn test_schema_added_and_missing() {
+    let left = Catalog {
+        schema_names: vec![String::from("test")],
+        ..Catalog::default()
+    };
+
+    let entries = compare(left.clone(), catalog(vec![], vec![]), Options::default()).await;
+    assert!(matches!(&entries[..], [SchemaAdded { schema_name }] if schema_name == "test"));
+
+    let entries = compare(left.clone(), left, Options::default()).await;
+    assert!(matches!(&entries[..], [SchemaMissing { schema_name }] if schema_name == "test"));
+}
+
+#[tokio::test]
+async fn test_table_added_and_removed() {
+    let left = catalog(vec![table("a")], vec![column("a", "id", 1, "integer")]);
+    let right = catalog(vec![table("b")], vec![column("b", "id", 1, "integer")]);
+
+    let entries = compare(left, right, Options::default()).await;
+
+    let SchemaMaintained { tables, .. } = &entries[0] else { panic!("schema not maintained") };
+    assert_eq!(2, tables.change_count());
+    assert!(matches!(&tables.entries[0], TableRemoved { table_name, .. } if table_name == "a"));
+    assert!(matches!(&tables.entries[1], TableAdded { table_name, definition, .. }
+        if table_name == "b" && definition == "CREATE TABLE test.b (\n    id integer\n)"));
+}
+
+#[tokio::test]
+async fn test_view_added_and_removed() {
+    let (left_table, left_view) = view("a");
+    let (right_table, right_view) = view("b");
+    let left = Catalog { views: vec![left_view], ..catalog(vec![left_table], vec![]) };
+    let right = Catalog { views: vec![right_view], ..catalog(vec![right_table], vec![]) };
+
+    let entries = compare(left, right, Options::default()).await;
+
+    let SchemaMaintained { tables, views, .. } = &entries[0] else { panic!("schema not maintained") };
+    assert!(tables.entries.is_empty());
+    assert_eq!(2, views.change_count());
+    assert!(matches!(&views.entries[0], ViewRemoved { view_name } if view_name == "a"));
+    assert!(matches!(&views.entries[1], ViewAdded { view_name, definition }
+        if view_name == "b" && definition == "CREATE VIEW test.b AS SELECT 1"));
+}
+
+#[tokio::test]
+async fn test_column_type_changed() {
+    let left = catalog(vec![table("a")], vec![column("a", "id", 1, "integer")]);
+    let right = catalog(vec![table("a")], vec![column("a", "id", 1, "bigint")]);
+
+    let entries = compare(left, right, Options::default()).await;
+
+    let SchemaMaintained { tables, .. } = &entries[0] else { panic!("schema not maintained") };
+    let TableMaintained { columns, .. } = &tables.entries[0] else { panic!("table not maintained") };
+    let ColumnMaintained { properties, .. } = &columns.entries[0] else { panic!("column not maintained") };
+
+    let changed: Vec<&str> = properties.entries.iter()
+        .filter_map(|p| match p {
+            PropertyChanged { property_name, .. } => Some(property_name.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(vec!["data_type", "column_type"], changed);
+}
+
+#[tokio::test]
+async fn test_ignore_column_ordinal() {
+    let left = catalog(vec![table("a")], vec![column("a", "x", 1, "integer"), column("a", "y", 2, "integer")]);
+    let right = catalog(vec![table("a")], vec![column("a", "y", 1, "integer"), column("a", "x", 2, "integer")]);
+
+    let entries = compare(left.clone(), right.clone(), Options::default()).await;
+    assert_eq!(2, entries[0].change_count());
+
+    let options = Options { ignore_column_ordinal: true, ..Options::default() };
+    let entries = compare(left, right, options).await;
+    assert_eq!(0, entries[0].change_count());
+}
+
+#[tokio::test]
+async fn test_user_type_changed() {
+    let user_type = |type_name: &str, enum_labels: &str| -> UserType {
+        serde_json::from_value(json!({
+            "type_schema": "test",
+            "type_name": type_name,
+            "type_kind": "ENUM",
+            "type_owner": "postgres",
+            "enum_labels": enum_labels,
+            "is_not_null": "NO",
+        })).unwrap()
+    };
+
+    let left = Catalog { user_types: vec![user_type("a", "'x'"), user_type("b", "'x'")], ..catalog(vec![], vec![]) };
+    let right = Catalog { user_types: vec![user_type("a", "'x', 'y'"), user_type("c", "'x'")], ..catalog(vec![], vec![]) };
+
+    let entries = compare(left, right, Options::default()).await;
+
+    let SchemaMaintained { types, .. } = &entries[0] else { panic!("schema not maintained") };
+    assert_eq!(3, types.change_count());
+    assert!(matches!(&types.entries[0], UserTypeMaintained { type_name, .. } if type_name == "a"));
+    assert!(matches!(&types.entries[1], UserTypeRemoved { type_name, .. } if type_name == "b"));
+    assert!(matches!(&types.entries[2], UserTypeAdded { type_name, definition, .. }
+        if type_name == "c" && definition == "CREATE TYPE test.c AS ENUM ('x')"));
+}
+
+#[tokio::test]
+async fn test_collapse_partitions() {
+    let parent = Table { partition_strategy: Some(String::from("LIST")), partition_key: Some(String::from("(id)")), ..table("a") };
+    let left = catalog(vec![parent.clone(), partition("a_1", "a", "FOR VALUES IN (1)")], vec![]);
+    let right = catalog(vec![parent, partition("a_1", "a", "FOR VALUES IN (1, 2)"), partition("a_3", "a", "FOR VALUES IN (3)")], vec![]);
+
+    let entries = compare(left.clone(), right.clone(), Options::default()).await;
+    let SchemaMaintained { tables, .. } = &entries[0] else { panic!("schema not maintained") };
+    assert_eq!(3, tables.entries.len());
+    assert_eq!(2, tables.change_count());
+
+    let options = Options { collapse_partitions: true, ..Options::default() };
+    let entries = compare(left, right, options).await;
+    let SchemaMaintained { tables, .. } = &entries[0] else { panic!("schema not maintained") };
+    let [TableMaintained { table_name, partitions, .. }] = &tables.entries[..] else { panic!("partitions not collapsed") };
+    assert_eq!("a", table_name);
+    assert_eq!(2, partitions.change_count());
+    assert!(matches!(&partitions.entries[0], PartitionMaintained { partition_name, .. } if partition_name == "a_1"));
+    assert!(matches!(&partitions.entries[1], PartitionAdded { partition_name, definition }
+        if partition_name == "a_3" && definition == "CREATE TABLE test.a_3 PARTITION OF test.a FOR VALUES IN (3)"));
+}
+
+#[tokio::test]
+async fn test_inherited_table_added() {
+    let child = Table { parents: Some(String::from("test.a")), ..table("b") };
+    let inherited = Column { is_local: String::from("NO"), inheritance_count: 1, ..column("b", "id", 1, "integer") };
+    let left = catalog(vec![table("a")], vec![column("a", "id", 1, "integer")]);
+    let right = catalog(vec![table("a"), child], vec![column("a", "id", 1, "integer"), inherited, column("b", "name", 2, "text")]);
+
+    let entries = compare(left, right, Options::default()).await;
+
+    let SchemaMaintained { tables, .. } = &entries[0] else { panic!("schema not maintained") };
+    assert!(matches!(&tables.entries[1], TableAdded { table_name, definition, .. }
+        if table_name == "b" && definition == "CREATE TABLE test.b (\n    name text\n) INHERITS (test.a)"));
+}
+
+#[tokio::test]
+async fn test_extensions() {
+    let extension = |extension_name: &str, extension_version: &str| -> Extension {
+        serde_json::from_value(json!({
+            "extension_name": extension_name,
+            "extension_version": extension_version,
+            "extension_schema": "test",
+            "is_relocatable": "YES",
+        })).unwrap()
+    };
+    let member: ExtensionMember = serde_json::from_value(json!({
+        "extension_name": "b",
+        "object_schema": "test",
+        "object_kind": "RELATION",
+        "object_name": "b_table",
+    })).unwrap();
+
+    let left = Catalog { extensions: vec![extension("a", "1.0")], ..catalog(vec![], vec![]) };
+    let right = Catalog {
+        extensions: vec![extension("a", "1.1"), extension("b", "2.0")],
+        extension_members: vec![member],
+        ..catalog(vec![table("b_table")], vec![])
+    };
+
+    let comparer = Comparer::new(Box::new(left.clone()), Box::new(right.clone()), Options::default());
+    let report = comparer.compare(vec![String::from("test")]).await.unwrap();
+    assert_eq!(3, report.change_count());
+    assert!(matches!(&report.extensions.entries[0], ExtensionMaintained { extension_name, .. } if extension_name == "a"));
+    assert!(matches!(&report.extensions.entries[1], ExtensionAdded { extension_name, definition }
+        if extension_name == "b" && definition == "CREATE EXTENSION b WITH SCHEMA test VERSION '2.0'"));
+
+    let options = Options { ignore_extension_objects: true, ..Options::default() };
+    let comparer = Comparer::new(Box::new(left), Box::new(right), options);
+    let report = comparer.compare(vec![String::from("test")]).await.unwrap();
+    assert_eq!(2, report.change_count());
+    assert!(!report.schemas.has_changes());
+}
+
+#[tokio::test]
+async fn test_summary() {
+    let left = catalog(vec![table("a"), table("b")], vec![column("a", "id", 1, "integer"), column("b", "id", 1, "integer")]);
+    let right = catalog(vec![table("a"), table("c")], vec![column("a", "id", 1, "bigint"), column("a", "name", 2, "text"), column("c", "id", 1, "integer")]);
+
+    let comparer = Comparer::new(Box::new(left), Box::new(right), Options::default());
+    let report = comparer.compare(vec![String::from("test")]).await.unwrap();
+    let summary = Summary::new(&report);
+
+    assert_eq!(ChangeTally { added: 1, removed: 1, changed: 0 }, summary.tables);
+    assert_eq!(ChangeTally { added: 1, removed: 0, changed: 1 }, summary.columns);
+    assert_eq!(0, summary.schemas.total());
+}
+
+#[tokio::test]
+async fn test_include_and_exclude() {
+    let left = catalog(vec![table("audit_log"), table("audit_tmp"), table("account")], vec![]);
+    let right = catalog(vec![], vec![]);
+    let options = Options {
+        include: vec!["table:audit_*".parse().unwrap()],
+        exclude: vec!["table:*_tmp".parse().unwrap()],
+        ..Options::default()
+    };
+
+    let entries = compare(left, right, options).await;
+
+    let SchemaMaintained { tables, .. } = &entries[0] else { panic!("schema not maintained") };
+    assert!(matches!(&tables.entries[..], [TableRemoved { table_name, .. }] if table_name == "audit_log"));
+    assert!("tables:audit_*".parse::<ObjectFilter>().is_err());
+}
+
+#[tokio::test]
+async fn test_filters_select_partitions_with_their_table() {
+    let parent = Table { partition_strategy: Some(String::from("RANGE")), partition_key: Some(String::from("(id)")), ..table("events") };
+    let sub_partitioned = Table { partition_strategy: Some(String::from("LIST")), ..partition("events_2024", "events", "FOR VALUES FROM (1) TO (100)") };
+    let left = catalog(vec![parent.clone(), sub_partitioned.clone(), table("account")], vec![]);
+    let right = catalog(vec![parent, sub_partitioned, partition("events_2024_a", "events_2024", "FOR VALUES IN (1)"), table("account")], vec![]);
+
+    let options = Options { include: vec!["table:events".parse().unwrap()], collapse_partitions: true, ..Options::default() };
+    let entries = compare(left.clone(), right.clone(), options).await;
+    let SchemaMaintained { tables, .. } = &entries[0] else { panic!("schema not maintained") };
+    let [TableMaintained { table_name, partitions, .. }] = &tables.entries[..] else { panic!("partitions not selected") };
+    assert_eq!("events", table_name);
+    assert!(matches!(&partitions.entries[..], [PartitionMaintained { .. }, PartitionAdded { partition_name, .. }] if partition_name == "events_2024_a"));
+
+    let options = Options { exclude: vec!["table:events".parse().unwrap()], ..Options::default() };
+    let entries = compare(left, right, options).await;
+    let SchemaMaintained { tables, .. } = &entries[0] else { panic!("schema not maintained") };
+    assert!(matches!(&tables.entries[..], [TableMaintained { table_name, .. }] if table_name == "account"));
+}
+
+#[tokio::test]
+async fn test_ignore_properties() {
+    let left = catalog(vec![table("a")], vec![column("a", "id", 1, "integer")]);
+    let right = catalog(vec![table("a")], vec![column("a", "id", 1, "bigint")]);
+    let options = Options { ignore_properties: vec![String::from("table.column.*_type")], ..Options::default() };
+
+    let entries = compare(left, right, options).await;
+
+    let SchemaMaintained { tables, .. } = &entries[0] else { panic!("schema not maintained") };
+    let TableMaintained { columns, .. } = &tables.entries[0] else { panic!("table not maintained") };
+    let ColumnMaintained { properties, .. } = &columns.entries[0] else { panic!("column not maintained") };
+    assert!(!properties.has_changes());
+    assert!(properties.entries.iter().any(|p| matches!(p, PropertyUnchanged { property_name, value } if property_name == "data_type" && value.as_deref() == Some("integer"))));
+}
+
+#[test]
+fn test_property_patterns() {
+    assert!(matches_property_path("*.owner", "schema", "schema_owner"));
+    assert!(matches_property_path("*_owner", "type", "type_owner"));
+    assert!(!matches_property_path("*.owner", "table.column", "column_type"));
+
+    assert!(is_known_property_pattern("table.column.collation_name"));
+    assert!(is_known_property_pattern("*.owner"));
+    assert!(!is_known_property_pattern("table.column.colation_name"));
+    assert!(!is_known_property_pattern("column.collation_name"));
+}
+
+#[tokio::test]
+async fn test_compared_properties_are_known() {
+    let row = |fields: serde_json::Value| {
+        let mut row = json!({ "table_schema": "test", "table_name": "a", "type_schema": "test", "type_name": "t" });
+        row.as_object_mut().unwrap().extend(fields.as_object().unwrap().clone());
+        row
+    };
+    let (view_table, view) = view("v");
+    let parent = Table { partition_strategy: Some(String::from("LIST")), partition_key: Some(String::from("(id)")), ..table("a") };
+    let catalog = Catalog {
+        extensions: vec![serde_json::from_value(json!({
+            "extension_name": "e", "extension_version": "1.0", "extension_schema": "test", "is_relocatable": "NO",
+        })).unwrap()],
+        indices: vec![
+            serde_json::from_value(row(json!({ "index_name": "a_i", "definition": "CREATE INDEX a_i ON test.a (id)" }))).unwrap(),
+            serde_json::from_value(row(json!({ "table_name": "m", "index_name": "m_i", "definition": "CREATE INDEX m_i ON test.m (id)" }))).unwrap(),
+        ],
+        materialized_views: vec![serde_json::from_value(json!({
+            "view_schema": "test", "view_name": "m", "view_owner": "postgres", "view_definition": " SELECT 1;", "is_populated": "YES",
+        })).unwrap()],
+        routines: vec![routine("r")],
+        sequences: vec![serde_json::from_value(json!({
+            "sequence_catalog": "postgres", "sequence_schema": "test", "sequence_name": "s", "data_type": "bigint",
+            "numeric_precision": 64, "numeric_precision_radix": 2, "numeric_scale": 0, "start_value": "1",
+            "minimum_value": "1", "maximum_value": "9223372036854775807", "increment": "1", "cycle_option": "NO",
+        })).unwrap()],
+        table_constraints: vec![serde_json::from_value(row(json!({
+            "constraint_catalog": "postgres", "constraint_schema": "test", "constraint_name": "a_c", "table_catalog": "postgres",
+            "constraint_type": "CHECK", "is_deferrable": "NO", "initially_deferred": "NO", "enforced": "YES", "definition": "CHECK (id > 0)",
+        }))).unwrap()],
+        table_policies: vec![serde_json::from_value(row(json!({
+            "policy_name": "a_p", "permissive": "PERMISSIVE", "roles": "{public}", "command": "SELECT", "using_expression": "true",
+        }))).unwrap()],
+        table_triggers: vec![serde_json::from_value(json!({
+            "trigger_catalog": "postgres", "trigger_schema": "test", "trigger_name": "a_t", "event_manipulation": "INSERT",
+            "event_object_catalog": "postgres", "event_object_schema": "test", "event_object_table": "a", "action_order": 1,
+            "action_statement": "EXECUTE FUNCTION test.r()", "action_orientation": "ROW", "action_timing": "AFTER",
+        })).unwrap()],
+        user_types: vec![serde_json::from_value(row(json!({
+            "type_kind": "COMPOSITE", "type_owner": "postgres", "is_not_null": "NO",
+        }))).unwrap()],
+        user_type_attributes: vec![serde_json::from_value(row(json!({
+            "attribute_name": "x", "ordinal_position": 1, "data_type": "integer",
+        }))).unwrap()],
+        user_type_constraints: vec![serde_json::from_value(row(json!({
+            "constraint_name": "t_c", "definition": "CHECK (VALUE > 0)", "is_validated": "YES",
+        }))).unwrap()],
+        views: vec![view],
+        ..catalog(vec![parent, partition("a_1", "a", "FOR VALUES IN (1)"), view_table], vec![column("a", "id", 1, "integer")])
+    };
+
+    let mut paths = HashSet::new();
+    for options in [Options::default(), Options { compare_routine_definitions: true, collapse_partitions: true, distinguish_inherited_columns: true, ..Options::default() }] {
+        let comparer = Comparer::new(Box::new(catalog.clone()), Box::new(catalog.clone()), options);
+        let report = serde_json::to_value(comparer.compare(vec![String::from("test")]).await.unwrap()).unwrap();
+        collect_property_paths(&report, &[], &mut paths);
+    }
+
+    let object_paths: HashSet<&str> = paths.iter().map(|p| &p[..p.rfind('.').unwrap()]).collect();
+    assert_eq!(17, object_paths.len(), "not every kind of object was compared: {:?}", object_paths);
+    for path in &paths {
+        assert!(is_known_property_pattern(path), "{} is compared but not a known property path", path);
+    }
+}
+
+// the paths, such as `table.column.collation_name`, of the properties in a JSON comparison report
+fn collect_property_paths(value: &serde_json::Value, object_path: &[&str], paths: &mut HashSet<String>) {
+    const OBJECT_KINDS: &[(&str, &str)] = &[
+        ("attributes", "attribute"), ("columns", "column"), ("constraints", "constraint"), ("extensions", "extension"),
+        ("indices", "index"), ("materialized_views", "materialized_view"), ("partitions", "partition"),
+        ("policies", "policy"), ("routines", "routine"), ("schemas", "schema"), ("sequences", "sequence"),
+        ("tables", "table"), ("triggers", "trigger"), ("types", "type"), ("views", "view"),
+    ];
+
+    match value {
+        serde_json::Value::Array(entries) => entries.iter().for_each(|e| collect_property_paths(e, object_path, paths)),
+        serde_json::Value::Object(fields) => for (key, field) in fields {
+            if key == "properties" {
+                for property in field.as_array().unwrap() {
+                    paths.insert(format!("{}.{}", object_path.join("."), property["property_name"].as_str().unwrap()));
+                }
+            } else if let Some((_, kind)) = OBJECT_KINDS.iter().find(|(k, _)| k == key) {
+                // objects within a schema are not prefixed by it
+                let parent_path = if object_path == ["schema"] { &[][..] } else { object_path };
+                collect_property_paths(field, &[parent_path, &[*kind]].concat(), paths);
+            }
+        },
+        _ => {},
+    }
+}
+
+#[tokio::test]
+async fn test_schema_map() {
+    let left = catalog(vec![table("a")], vec![column("a", "id", 1, "integer")]);
+    let right = catalog_in("template", vec![table("a")], vec![column("a", "id", 1, "integer"), column("a", "name", 2, "text")], vec![]);
+    let options = Options { schema_map: HashMap::from([(String::from("test"), String::from("template"))]), ..Options::default() };
+
+    let entries = compare(left, right, options).await;
+
+    let SchemaMaintained { schema_name, tables, .. } = &entries[0] else { panic!("schema not maintained") };
+    assert_eq!("test", schema_name);
+    let TableMaintained { columns, .. } = &tables.entries[0] else { panic!("table not maintained") };
+    assert!(matches!(&columns.entries[..], [ColumnMaintained { .. }, ColumnAdded { column_name, .. }] if column_name == "name"));
+}
+
+#[tokio::test]
+async fn test_schema_map_shares_a_right_schema() {
+    let mut left = catalog_in("tenant_a", vec![table("a")], vec![column("a", "id", 1, "integer")], vec![]);
+    left.append(catalog_in("tenant_b", vec![table("a")], vec![column("a", "id", 1, "bigint")], vec![]));
+    let right = catalog_in("template", vec![table("a")], vec![column("a", "id", 1, "integer")], vec![]);
+    let options = Options {
+        schema_map: HashMap::from([
+            (String::from("tenant_a"), String::from("template")),
+            (String::from("tenant_b"), String::from("template")),
+        ]),
+        ..Options::default()
+    };
+
+    let comparer = Comparer::new(Box::new(left), Box::new(right), options);
+    let entries = comparer.compare(vec![String::from("tenant_a"), String::from("tenant_b")]).await.unwrap().schemas.entries;
+
+    assert!(matches!(&entries[..], [SchemaMaintained { schema_name: a, .. }, SchemaMaintained { schema_name: b, .. }]
+        if a == "tenant_a" && b == "tenant_b"));
+    assert!(!entries[0].has_changes());
+    assert!(entries[1].has_changes());
+}
+
+#[tokio::test]
+async fn test_schema_map_rewrites_qualified_names() {
+    let id = |schema_name: &str| Column { column_default: Some(format!("nextval('{}.a_id_seq'::regclass)", schema_name)), ..column("a", "id", 1, "integer") };
+    let count = |schema_name: &str| Routine {
+        routine_definition: Some(format!("SELECT count(*) FROM {}.a", schema_name)),
+        configuration: Some(format!("search_path={}, public; work_mem=64MB", schema_name)),
+        ..routine("a_count")
+    };
+    let left = Catalog { routines: vec![count("test")], ..catalog(vec![table("a")], vec![id("test")]) };
+    let right = catalog_in("template", vec![table("a")], vec![id("template")], vec![count("template")]);
+    let options = Options { schema_map: HashMap::from([(String::from("test"), String::from("template"))]), ..Options::default() };
+
+    let entries = compare(left, right, options).await;
+
+    assert!(matches!(&entries[..], [SchemaMaintained { schema_name, .. }] if schema_name == "test"));
+    assert!(!entries[0].has_changes());
+}
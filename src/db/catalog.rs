@@ -0,0 +1,394 @@
+use std::collections::HashMap;
+use futures::future;
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use sqlx::Error;
+use crate::db::column::Column;
+use crate::db::column_privilege::ColumnPrivilege;
+use crate::db::extension::Extension;
+use crate::db::extension_member::ExtensionMember;
+use crate::db::index::Index;
+use crate::db::materialized_view::MaterializedView;
+use crate::db::materialized_view_privilege::MaterializedViewPrivilege;
+use crate::db::routine::Routine;
+use crate::db::routine_parameters::RoutineParameter;
+use crate::db::routine_privilege::RoutinePrivilege;
+use crate::db::schema::Schema;
+use crate::db::sequence::Sequence;
+use crate::db::source::SchemaSource;
+use crate::db::table::Table;
+use crate::db::table_constraint::TableConstraint;
+use crate::db::table_policy::TablePolicy;
+use crate::db::table_privilege::TablePrivilege;
+use crate::db::table_trigger::TableTrigger;
+use crate::db::user_type::UserType;
+use crate::db::user_type_attribute::UserTypeAttribute;
+use crate::db::user_type_constraint::UserTypeConstraint;
+use crate::db::user_type_privilege::UserTypePrivilege;
+use crate::db::view::View;
+use crate::string::{replace_schema_qualifiers, replace_search_path_schemas};
+
+/// Catalog rows held in memory, as captured from a database or built as a fixture.
+///
+/// Only the schemas listed in `schema_names` can be queried. Extensions are those of the whole database.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Catalog {
+    pub schema_names: Vec<String>,
+    pub columns: Vec<Column>,
+    pub column_privileges: Vec<ColumnPrivilege>,
+    pub extensions: Vec<Extension>,
+    pub extension_members: Vec<ExtensionMember>,
+    pub indices: Vec<Index>,
+    pub materialized_views: Vec<MaterializedView>,
+    pub materialized_view_privileges: Vec<MaterializedViewPrivilege>,
+    pub routines: Vec<Routine>,
+    pub routine_parameters: Vec<RoutineParameter>,
+    pub routine_privileges: Vec<RoutinePrivilege>,
+    pub schemas: Vec<Schema>,
+    pub sequences: Vec<Sequence>,
+    pub tables: Vec<Table>,
+    pub table_constraints: Vec<TableConstraint>,
+    pub table_policies: Vec<TablePolicy>,
+    pub table_privileges: Vec<TablePrivilege>,
+    pub table_triggers: Vec<TableTrigger>,
+    pub user_types: Vec<UserType>,
+    pub user_type_attributes: Vec<UserTypeAttribute>,
+    pub user_type_constraints: Vec<UserTypeConstraint>,
+    pub user_type_privileges: Vec<UserTypePrivilege>,
+    pub views: Vec<View>,
+}
+
+impl Catalog {
+    pub async fn capture(source: &dyn SchemaSource, schema_names: &[String]) -> Result<Catalog, Error> {
+        let (columns, column_privileges, extensions, extension_members, indices, materialized_views, materialized_view_privileges, routines, routine_parameters, routine_privileges, schemas, sequences, tables, table_constraints, table_policies, table_privileges, table_triggers, user_types, user_type_attributes, user_type_constraints, user_type_privileges, views) = futures::try_join!(
+            source.columns(schema_names),
+            source.column_privileges(schema_names),
+            source.extensions(),
+            source.extension_members(schema_names),
+            source.indices(schema_names),
+            source.materialized_views(schema_names),
+            source.materialized_view_privileges(schema_names),
+            source.routines(schema_names),
+            source.routine_parameters(schema_names),
+            source.routine_privileges(schema_names),
+            source.schemas(schema_names),
+            source.sequences(schema_names),
+            source.tables(schema_names),
+            source.table_constraints(schema_names),
+            source.table_policies(schema_names),
+            source.table_privileges(schema_names),
+            source.table_triggers(schema_names),
+            source.user_types(schema_names),
+            source.user_type_attributes(schema_names),
+            source.user_type_constraints(schema_names),
+            source.user_type_privileges(schema_names),
+            source.views(schema_names))?;
+
+        Ok(Catalog {
+            schema_names: schema_names.to_vec(),
+            columns,
+            column_privileges,
+            extensions,
+            extension_members,
+            indices,
+            materialized_views,
+            materialized_view_privileges,
+            routines,
+            routine_parameters,
+            routine_privileges,
+            schemas,
+            sequences,
+            tables,
+            table_constraints,
+            table_policies,
+            table_privileges,
+            table_triggers,
+            user_types,
+            user_type_attributes,
+            user_type_constraints,
+            user_type_privileges,
+            views,
+        })
+    }
+
+    /// Adds the schemas of another catalog. Extensions, which belong to the whole database, are kept as they are.
+    pub fn append(&mut self, other: Catalog) {
+        self.schema_names.extend(other.schema_names);
+        self.columns.extend(other.columns);
+        self.column_privileges.extend(other.column_privileges);
+        self.extension_members.extend(other.extension_members);
+        self.indices.extend(other.indices);
+        self.materialized_views.extend(other.materialized_views);
+        self.materialized_view_privileges.extend(other.materialized_view_privileges);
+        self.routines.extend(other.routines);
+        self.routine_parameters.extend(other.routine_parameters);
+        self.routine_privileges.extend(other.routine_privileges);
+        self.schemas.extend(other.schemas);
+        self.sequences.extend(other.sequences);
+        self.tables.extend(other.tables);
+        self.table_constraints.extend(other.table_constraints);
+        self.table_policies.extend(other.table_policies);
+        self.table_privileges.extend(other.table_privileges);
+        self.table_triggers.extend(other.table_triggers);
+        self.user_types.extend(other.user_types);
+        self.user_type_attributes.extend(other.user_type_attributes);
+        self.user_type_constraints.extend(other.user_type_constraints);
+        self.user_type_privileges.extend(other.user_type_privileges);
+        self.views.extend(other.views);
+    }
+
+    /// Renames schemas, both in the fields naming a schema and in the qualified names within definitions, defaults,
+    /// expressions and type names, and in the `search_path` of routine configurations.
+    pub fn rename_schemas(&mut self, renames: &HashMap<String, String>) {
+        self.schema_names.iter_mut().for_each(|s| rename_schema(s, renames));
+
+        for column in &mut self.columns {
+            rename_schema(&mut column.table_schema, renames);
+            rename_optional_schema(&mut column.collation_schema, renames);
+            rename_optional_schema(&mut column.domain_schema, renames);
+            rename_optional_schema(&mut column.udt_schema, renames);
+            rename_qualifiers(&mut column.column_type, renames);
+            rename_optional_qualifiers(&mut column.column_default, renames);
+            rename_optional_qualifiers(&mut column.generation_expression, renames);
+        }
+
+        for privilege in &mut self.column_privileges {
+            rename_schema(&mut privilege.table_schema, renames);
+        }
+
+        for extension in &mut self.extensions {
+            rename_schema(&mut extension.extension_schema, renames);
+        }
+
+        for member in &mut self.extension_members {
+            rename_schema(&mut member.object_schema, renames);
+        }
+
+        for index in &mut self.indices {
+            rename_schema(&mut index.table_schema, renames);
+            rename_qualifiers(&mut index.definition, renames);
+        }
+
+        for view in &mut self.materialized_views {
+            rename_schema(&mut view.view_schema, renames);
+            rename_optional_qualifiers(&mut view.view_definition, renames);
+        }
+
+        for privilege in &mut self.materialized_view_privileges {
+            rename_schema(&mut privilege.view_schema, renames);
+        }
+
+        for routine in &mut self.routines {
+            rename_schema(&mut routine.specific_schema, renames);
+            rename_schema(&mut routine.routine_schema, renames);
+            rename_optional_schema(&mut routine.type_udt_schema, renames);
+            rename_optional_qualifiers(&mut routine.routine_definition, renames);
+            rename_optional_qualifiers(&mut routine.definition, renames);
+
+            if let Some(configuration) = &mut routine.configuration {
+                *configuration = replace_search_path_schemas(configuration, renames);
+            }
+        }
+
+        for parameter in &mut self.routine_parameters {
+            rename_schema(&mut parameter.specific_schema, renames);
+            rename_schema(&mut parameter.udt_schema, renames);
+            rename_optional_qualifiers(&mut parameter.parameter_default, renames);
+        }
+
+        for privilege in &mut self.routine_privileges {
+            rename_schema(&mut privilege.specific_schema, renames);
+            rename_schema(&mut privilege.routine_schema, renames);
+        }
+
+        for schema in &mut self.schemas {
+            rename_schema(&mut schema.schema_name, renames);
+        }
+
+        for sequence in &mut self.sequences {
+            rename_schema(&mut sequence.sequence_schema, renames);
+        }
+
+        for table in &mut self.tables {
+            rename_schema(&mut table.table_schema, renames);
+            rename_optional_schema(&mut table.user_defined_type_schema, renames);
+            rename_optional_schema(&mut table.partition_parent_schema, renames);
+            rename_optional_qualifiers(&mut table.parents, renames);
+        }
+
+        for constraint in &mut self.table_constraints {
+            rename_schema(&mut constraint.constraint_schema, renames);
+            rename_schema(&mut constraint.table_schema, renames);
+            rename_optional_schema(&mut constraint.referenced_table_schema, renames);
+            rename_optional_qualifiers(&mut constraint.definition, renames);
+        }
+
+        for policy in &mut self.table_policies {
+            rename_schema(&mut policy.table_schema, renames);
+            rename_optional_qualifiers(&mut policy.using_expression, renames);
+            rename_optional_qualifiers(&mut policy.check_expression, renames);
+        }
+
+        for privilege in &mut self.table_privileges {
+            rename_schema(&mut privilege.table_schema, renames);
+        }
+
+        for trigger in &mut self.table_triggers {
+            rename_schema(&mut trigger.trigger_schema, renames);
+            rename_schema(&mut trigger.event_object_schema, renames);
+            rename_optional_qualifiers(&mut trigger.action_condition, renames);
+            rename_qualifiers(&mut trigger.action_statement, renames);
+            rename_optional_qualifiers(&mut trigger.definition, renames);
+        }
+
+        for user_type in &mut self.user_types {
+            rename_schema(&mut user_type.type_schema, renames);
+            rename_optional_qualifiers(&mut user_type.base_type, renames);
+            rename_optional_qualifiers(&mut user_type.default_value, renames);
+            rename_optional_qualifiers(&mut user_type.range_subtype, renames);
+        }
+
+        for attribute in &mut self.user_type_attributes {
+            rename_schema(&mut attribute.type_schema, renames);
+            rename_qualifiers(&mut attribute.data_type, renames);
+        }
+
+        for constraint in &mut self.user_type_constraints {
+            rename_schema(&mut constraint.type_schema, renames);
+            rename_qualifiers(&mut constraint.definition, renames);
+        }
+
+        for privilege in &mut self.user_type_privileges {
+            rename_schema(&mut privilege.type_schema, renames);
+        }
+
+        for view in &mut self.views {
+            rename_schema(&mut view.table_schema, renames);
+            rename_optional_qualifiers(&mut view.view_definition, renames);
+        }
+    }
+}
+
+fn rename_schema(schema_name: &mut String, renames: &HashMap<String, String>) {
+    if let Some(renamed) = renames.get(schema_name.as_str()) {
+        *schema_name = renamed.clone();
+    }
+}
+
+fn rename_optional_schema(schema_name: &mut Option<String>, renames: &HashMap<String, String>) {
+    if let Some(schema_name) = schema_name {
+        rename_schema(schema_name, renames);
+    }
+}
+
+fn rename_qualifiers(text: &mut String, renames: &HashMap<String, String>) {
+    *text = replace_schema_qualifiers(text, renames);
+}
+
+fn rename_optional_qualifiers(text: &mut Option<String>, renames: &HashMap<String, String>) {
+    if let Some(text) = text {
+        rename_qualifiers(text, renames);
+    }
+}
+
+impl SchemaSource for Catalog {
+    fn columns<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Column>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.columns, schema_names, |c| &c.table_schema))))
+    }
+
+    fn column_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<ColumnPrivilege>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.column_privileges, schema_names, |p| &p.table_schema))))
+    }
+
+    fn extensions(&self) -> BoxFuture<'_, Result<Vec<Extension>, Error>> {
+        Box::pin(future::ready(Ok(self.extensions.clone())))
+    }
+
+    fn extension_members<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<ExtensionMember>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.extension_members, schema_names, |m| &m.object_schema))))
+    }
+
+    fn indices<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Index>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.indices, schema_names, |i| &i.table_schema))))
+    }
+
+    fn materialized_views<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<MaterializedView>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.materialized_views, schema_names, |v| &v.view_schema))))
+    }
+
+    fn materialized_view_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<MaterializedViewPrivilege>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.materialized_view_privileges, schema_names, |p| &p.view_schema))))
+    }
+
+    fn routines<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Routine>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.routines, schema_names, |r| &r.routine_schema))))
+    }
+
+    fn routine_parameters<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<RoutineParameter>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.routine_parameters, schema_names, |p| &p.specific_schema))))
+    }
+
+    fn routine_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<RoutinePrivilege>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.routine_privileges, schema_names, |p| &p.routine_schema))))
+    }
+
+    fn schemas<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Schema>, Error>> {
+        let result = match schema_names.iter().find(|s| !self.schema_names.contains(s)) {
+            Some(schema_name) => Err(Error::Configuration(format!("schema '{}' is not in the catalog", schema_name).into())),
+            None => Ok(in_schemas(&self.schemas, schema_names, |s| &s.schema_name)),
+        };
+
+        Box::pin(future::ready(result))
+    }
+
+    fn sequences<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Sequence>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.sequences, schema_names, |s| &s.sequence_schema))))
+    }
+
+    fn tables<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Table>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.tables, schema_names, |t| &t.table_schema))))
+    }
+
+    fn table_constraints<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<TableConstraint>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.table_constraints, schema_names, |c| &c.table_schema))))
+    }
+
+    fn table_policies<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<TablePolicy>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.table_policies, schema_names, |p| &p.table_schema))))
+    }
+
+    fn table_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<TablePrivilege>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.table_privileges, schema_names, |p| &p.table_schema))))
+    }
+
+    fn table_triggers<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<TableTrigger>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.table_triggers, schema_names, |t| &t.trigger_schema))))
+    }
+
+    fn user_types<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<UserType>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.user_types, schema_names, |t| &t.type_schema))))
+    }
+
+    fn user_type_attributes<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<UserTypeAttribute>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.user_type_attributes, schema_names, |a| &a.type_schema))))
+    }
+
+    fn user_type_constraints<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<UserTypeConstraint>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.user_type_constraints, schema_names, |c| &c.type_schema))))
+    }
+
+    fn user_type_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<UserTypePrivilege>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.user_type_privileges, schema_names, |p| &p.type_schema))))
+    }
+
+    fn views<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<View>, Error>> {
+        Box::pin(future::ready(Ok(in_schemas(&self.views, schema_names, |v| &v.table_schema))))
+    }
+}
+
+fn in_schemas<T: Clone>(rows: &[T], schema_names: &[String], schema_name: fn(&T) -> &String) -> Vec<T> {
+    rows.iter()
+        .filter(|r| schema_names.contains(schema_name(r)))
+        .cloned()
+        .collect()
+}
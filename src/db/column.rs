@@ -1,68 +1,76 @@
+use serde::{Deserialize, Serialize};
 use sqlx::{Error, FromRow, PgConnection};
 
 const QUERY: &str = r#"
 SELECT
-    table_catalog,
-    table_schema,
-    table_name,
-    column_name,
-    ordinal_position,
-    column_default,
-    is_nullable,
-    data_type,
-    character_maximum_length,
-    character_octet_length,
-    numeric_precision,
-    numeric_precision_radix,
-    numeric_scale,
-    datetime_precision,
-    interval_type,
-    interval_precision,
-    character_set_catalog,
-    character_set_schema,
-    character_set_name,
-    collation_catalog,
-    collation_schema,
-    collation_name,
-    domain_catalog,
-    domain_schema,
-    domain_name,
-    udt_catalog,
-    udt_schema,
-    udt_name,
-    scope_catalog,
-    scope_schema,
-    scope_name,
-    maximum_cardinality,
-    dtd_identifier,
-    is_self_referencing,
-    is_identity,
-    identity_generation,
-    identity_start,
-    identity_increment,
-    identity_maximum,
-    identity_minimum,
-    identity_cycle,
-    is_generated,
-    generation_expression,
-    is_updatable
+    c.table_catalog,
+    c.table_schema,
+    c.table_name,
+    c.column_name,
+    c.ordinal_position,
+    c.column_default,
+    c.is_nullable,
+    c.data_type,
+    pg_catalog.format_type(a.atttypid, a.atttypmod) AS column_type,
+    c.character_maximum_length,
+    c.character_octet_length,
+    c.numeric_precision,
+    c.numeric_precision_radix,
+    c.numeric_scale,
+    c.datetime_precision,
+    c.interval_type,
+    c.interval_precision,
+    c.character_set_catalog,
+    c.character_set_schema,
+    c.character_set_name,
+    c.collation_catalog,
+    c.collation_schema,
+    c.collation_name,
+    c.domain_catalog,
+    c.domain_schema,
+    c.domain_name,
+    c.udt_catalog,
+    c.udt_schema,
+    c.udt_name,
+    c.scope_catalog,
+    c.scope_schema,
+    c.scope_name,
+    c.maximum_cardinality,
+    c.dtd_identifier,
+    c.is_self_referencing,
+    c.is_identity,
+    c.identity_generation,
+    c.identity_start,
+    c.identity_increment,
+    c.identity_maximum,
+    c.identity_minimum,
+    c.identity_cycle,
+    c.is_generated,
+    c.generation_expression,
+    c.is_updatable,
+    CASE WHEN a.attislocal THEN 'YES' ELSE 'NO' END AS is_local,
+    a.attinhcount::integer AS inheritance_count
 FROM
-    information_schema.columns
+    information_schema.columns c
+    LEFT JOIN pg_catalog.pg_namespace n ON n.nspname = c.table_schema
+    LEFT JOIN pg_catalog.pg_class t ON t.relnamespace = n.oid AND t.relname = c.table_name
+    LEFT JOIN pg_catalog.pg_attribute a ON a.attrelid = t.oid AND a.attname = c.column_name
 WHERE
-    table_schema = ANY($1)
+    c.table_schema = ANY($1)
 ORDER BY
-    table_catalog,
-    table_schema,
-    table_name,
-    column_name;"#;
+    c.table_catalog,
+    c.table_schema,
+    c.table_name,
+    c.column_name;"#;
 
+#[allow(clippy::redundant_slicing)]
 pub async fn columns(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<Column>, Error> {
     sqlx::query_as(QUERY)
         .bind(&schema_names[..])
         .fetch_all(connection).await
 }
 
-#[derive(Debug, Clone, PartialEq, FromRow)]
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
 pub struct Column {
     pub table_catalog: String,
     pub table_schema: String,
@@ -72,6 +80,7 @@ pub struct Column {
     pub column_default: Option<String>,
     pub is_nullable: String,
     pub data_type: String,
+    pub column_type: String,
     pub character_maximum_length: Option<i32>,
     pub character_octet_length: Option<i32>,
     pub numeric_precision: Option<i32>,
@@ -108,5 +117,7 @@ pub struct Column {
     pub is_generated: String,
     pub generation_expression: Option<String>,
     pub is_updatable: String,
+    pub is_local: String,
+    pub inheritance_count: i32,
 }
 
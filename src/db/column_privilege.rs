@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use sqlx::{Error, FromRow, PgConnection};
 use crate::db::privilege::Privilege;
 
@@ -24,13 +25,14 @@ ORDER BY
     grantee,
     privilege_type;"#;
 
+#[allow(clippy::redundant_slicing)]
 pub async fn column_privileges(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<ColumnPrivilege>, Error> {
     sqlx::query_as(QUERY)
         .bind(&schema_names[..])
         .fetch_all(connection).await
 }
 
-#[derive(Debug, Clone, PartialEq, FromRow)]
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
 pub struct ColumnPrivilege {
     pub grantor: String,
     pub grantee: String,
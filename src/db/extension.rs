@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{Error, FromRow, PgConnection};
+
+const QUERY: &str = r#"
+SELECT
+    e.extname AS extension_name,
+    e.extversion AS extension_version,
+    n.nspname AS extension_schema,
+    CASE WHEN e.extrelocatable THEN 'YES' ELSE 'NO' END AS is_relocatable
+FROM
+    pg_catalog.pg_extension e
+    JOIN pg_catalog.pg_namespace n ON n.oid = e.extnamespace
+ORDER BY
+    extension_name;"#;
+
+pub async fn query(connection: &mut PgConnection) -> Result<Vec<Extension>, Error> {
+    sqlx::query_as(QUERY)
+        .fetch_all(connection).await
+}
+
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
+pub struct Extension {
+    pub extension_name: String,
+    pub extension_version: String,
+    pub extension_schema: String,
+    pub is_relocatable: String,
+}
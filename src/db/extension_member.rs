@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{Error, FromRow, PgConnection};
+
+// object names match those of the routine, table and type queries
+const QUERY: &str = r#"
+SELECT
+    e.extname AS extension_name,
+    n.nspname AS object_schema,
+    'ROUTINE' AS object_kind,
+    p.proname || '_' || p.oid AS object_name
+FROM
+    pg_catalog.pg_depend d
+    JOIN pg_catalog.pg_extension e ON e.oid = d.refobjid
+    JOIN pg_catalog.pg_proc p ON p.oid = d.objid
+    JOIN pg_catalog.pg_namespace n ON n.oid = p.pronamespace
+WHERE
+    d.deptype = 'e' AND
+    d.refclassid = 'pg_catalog.pg_extension'::regclass AND
+    d.classid = 'pg_catalog.pg_proc'::regclass AND
+    n.nspname = ANY($1)
+UNION ALL
+SELECT
+    e.extname,
+    n.nspname,
+    'RELATION',
+    c.relname
+FROM
+    pg_catalog.pg_depend d
+    JOIN pg_catalog.pg_extension e ON e.oid = d.refobjid
+    JOIN pg_catalog.pg_class c ON c.oid = d.objid
+    JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+WHERE
+    d.deptype = 'e' AND
+    d.refclassid = 'pg_catalog.pg_extension'::regclass AND
+    d.classid = 'pg_catalog.pg_class'::regclass AND
+    n.nspname = ANY($1)
+UNION ALL
+SELECT
+    e.extname,
+    n.nspname,
+    'TYPE',
+    t.typname
+FROM
+    pg_catalog.pg_depend d
+    JOIN pg_catalog.pg_extension e ON e.oid = d.refobjid
+    JOIN pg_catalog.pg_type t ON t.oid = d.objid
+    JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+WHERE
+    d.deptype = 'e' AND
+    d.refclassid = 'pg_catalog.pg_extension'::regclass AND
+    d.classid = 'pg_catalog.pg_type'::regclass AND
+    n.nspname = ANY($1)
+ORDER BY
+    extension_name,
+    object_schema,
+    object_kind,
+    object_name;"#;
+
+pub async fn query(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<ExtensionMember>, Error> {
+    sqlx::query_as(QUERY)
+        .bind(schema_names)
+        .fetch_all(connection).await
+}
+
+/// An object created by an extension, identified by the name used in the corresponding catalog query.
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
+pub struct ExtensionMember {
+    pub extension_name: String,
+    pub object_schema: String,
+    pub object_kind: String,
+    pub object_name: String,
+}
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use sqlx::{Error, FromRow, PgConnection};
 
 const QUERY: &str = r#"SELECT
@@ -15,13 +16,14 @@ ORDER BY
     table_name,
     index_name;"#;
 
+#[allow(clippy::redundant_slicing)]
 pub async fn indices(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<Index>, Error> {
     sqlx::query_as(QUERY)
         .bind(&schema_names[..])
         .fetch_all(connection).await
 }
 
-#[derive(Debug, Clone, PartialEq, FromRow)]
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
 pub struct Index {
     pub table_schema: String,
     pub table_name: String,
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{Error, FromRow, PgConnection};
+
+const QUERY: &str = r#"
+SELECT
+    m.schemaname AS view_schema,
+    m.matviewname AS view_name,
+    m.matviewowner AS view_owner,
+    m.tablespace AS table_space,
+    m.definition AS view_definition,
+    CASE WHEN m.ispopulated THEN 'YES' ELSE 'NO' END AS is_populated
+FROM
+    pg_catalog.pg_matviews m
+WHERE
+    m.schemaname = ANY($1)
+ORDER BY
+    view_schema,
+    view_name;"#;
+
+pub async fn query(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<MaterializedView>, Error> {
+    sqlx::query_as(QUERY)
+        .bind(schema_names)
+        .fetch_all(connection).await
+}
+
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
+pub struct MaterializedView {
+    pub view_schema: String,
+    pub view_name: String,
+    pub view_owner: String,
+    pub table_space: Option<String>,
+    pub view_definition: Option<String>,
+    pub is_populated: String,
+}
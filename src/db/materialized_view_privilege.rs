@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{Error, FromRow, PgConnection};
+use crate::db::privilege::Privilege;
+
+const QUERY: &str = r#"
+SELECT
+    pg_catalog.pg_get_userbyid(a.grantor) AS grantor,
+    CASE WHEN a.grantee = 0 THEN 'PUBLIC' ELSE pg_catalog.pg_get_userbyid(a.grantee) END AS grantee,
+    n.nspname AS view_schema,
+    c.relname AS view_name,
+    a.privilege_type,
+    CASE WHEN a.is_grantable THEN 'YES' ELSE 'NO' END AS is_grantable
+FROM
+    pg_catalog.pg_class c
+    JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+    CROSS JOIN LATERAL pg_catalog.aclexplode(COALESCE(c.relacl, pg_catalog.acldefault('r', c.relowner))) a
+WHERE
+    n.nspname = ANY($1) AND
+    c.relkind = 'm'
+ORDER BY
+    view_schema,
+    view_name,
+    grantor,
+    grantee,
+    privilege_type;"#;
+
+pub async fn query(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<MaterializedViewPrivilege>, Error> {
+    sqlx::query_as(QUERY)
+        .bind(schema_names)
+        .fetch_all(connection).await
+}
+
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
+pub struct MaterializedViewPrivilege {
+    pub grantor: String,
+    pub grantee: String,
+    pub view_schema: String,
+    pub view_name: String,
+    pub privilege_type: String,
+    pub is_grantable: String,
+}
+
+impl Privilege for &MaterializedViewPrivilege {
+    fn grantor(&self) -> &str {
+        &self.grantor
+    }
+
+    fn grantee(&self) -> &str {
+        &self.grantee
+    }
+
+    fn privilege_type(&self) -> &str {
+        &self.privilege_type
+    }
+}
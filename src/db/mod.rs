@@ -1,91 +1,186 @@
+pub mod catalog;
 pub mod column;
 pub mod column_privilege;
+pub mod extension;
+pub mod extension_member;
 pub mod index;
+pub mod materialized_view;
+pub mod materialized_view_privilege;
 pub mod privilege;
 pub mod routine;
 pub mod routine_parameters;
 pub mod routine_privilege;
 pub mod schema;
 pub mod sequence;
+pub mod snapshot;
+pub mod source;
 pub mod table;
 pub mod table_constraint;
+pub mod table_policy;
 pub mod table_privilege;
 pub mod table_trigger;
+pub mod user_type;
+pub mod user_type_attribute;
+pub mod user_type_constraint;
+pub mod user_type_privilege;
 pub mod view;
 
-use sqlx::{Connection, Error, PgConnection};
+use futures::future::BoxFuture;
+use sqlx::{Error, PgConnection, PgPool, Postgres, Transaction};
+use sqlx::postgres::PgPoolOptions;
 use crate::db::column::Column;
 use crate::db::column_privilege::ColumnPrivilege;
+use crate::db::extension::Extension;
+use crate::db::extension_member::ExtensionMember;
 use crate::db::index::Index;
+use crate::db::materialized_view::MaterializedView;
+use crate::db::materialized_view_privilege::MaterializedViewPrivilege;
 use crate::db::routine::Routine;
 use crate::db::routine_parameters::RoutineParameter;
 use crate::db::routine_privilege::RoutinePrivilege;
+use crate::db::schema::Schema;
+use crate::db::sequence::Sequence;
+use crate::db::source::SchemaSource;
+use crate::db::table::Table;
+use crate::db::table_constraint::TableConstraint;
+use crate::db::table_policy::TablePolicy;
 use crate::db::table_privilege::TablePrivilege;
 use crate::db::table_trigger::TableTrigger;
+use crate::db::user_type::UserType;
+use crate::db::user_type_attribute::UserTypeAttribute;
+use crate::db::user_type_constraint::UserTypeConstraint;
+use crate::db::user_type_privilege::UserTypePrivilege;
 use crate::db::view::View;
 
+const MAX_CONNECTIONS: u32 = 4;
+
 pub struct Database {
-    connection: PgConnection
+    pool: PgPool,
+    // keeps the exported snapshot valid for as long as the database is in use
+    _snapshot_connection: PgConnection,
+    snapshot_id: String,
 }
 
 impl Database {
     pub async fn connect(url: &str) -> Result<Database, Error> {
-        let connection = PgConnection::connect(url).await?;
+        let pool = PgPoolOptions::new()
+            .max_connections(MAX_CONNECTIONS)
+            .connect(url).await?;
+
+        let mut snapshot_connection = pool.acquire().await?.detach();
+
+        sqlx::query("BEGIN ISOLATION LEVEL REPEATABLE READ READ ONLY")
+            .execute(&mut snapshot_connection).await?;
+
+        let snapshot_id = sqlx::query_scalar("SELECT pg_catalog.pg_export_snapshot()")
+            .fetch_one(&mut snapshot_connection).await?;
 
         Ok(Database {
-            connection,
+            pool,
+            _snapshot_connection: snapshot_connection,
+            snapshot_id,
         })
     }
 
-    pub async fn columns(&mut self, schema_names: &[String]) -> Result<Vec<Column>, Error> {
-        column::columns(&mut self.connection, schema_names).await
+    async fn transaction(&self) -> Result<Transaction<'static, Postgres>, Error> {
+        let mut transaction = self.pool.begin().await?;
+
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+            .execute(&mut *transaction).await?;
+
+        sqlx::query(&format!("SET TRANSACTION SNAPSHOT '{}'", self.snapshot_id))
+            .execute(&mut *transaction).await?;
+
+        Ok(transaction)
     }
+}
 
-    pub async fn column_privileges(&mut self, schema_names: &[String]) -> Result<Vec<ColumnPrivilege>, Error> {
-        column_privilege::column_privileges(&mut self.connection, schema_names).await
+impl SchemaSource for Database {
+    fn columns<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Column>, Error>> {
+        Box::pin(async move { column::columns(&mut *self.transaction().await?, schema_names).await })
     }
 
-    pub async fn indices(&mut self, schema_names: &[String]) -> Result<Vec<Index>, Error> {
-        index::indices(&mut self.connection, schema_names).await
+    fn column_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<ColumnPrivilege>, Error>> {
+        Box::pin(async move { column_privilege::column_privileges(&mut *self.transaction().await?, schema_names).await })
     }
 
-    pub async fn routines(&mut self, schema_names: &[String]) -> Result<Vec<Routine>, Error> {
-        routine::routines(&mut self.connection, schema_names).await
+    fn extensions(&self) -> BoxFuture<'_, Result<Vec<Extension>, Error>> {
+        Box::pin(async move { extension::query(&mut *self.transaction().await?).await })
     }
-    
-    pub async fn routine_parameters(&mut self, schema_names: &[String]) -> Result<Vec<RoutineParameter>, Error> {
-        routine_parameters::routine_parameters(&mut self.connection, schema_names).await
+
+    fn extension_members<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<ExtensionMember>, Error>> {
+        Box::pin(async move { extension_member::query(&mut *self.transaction().await?, schema_names).await })
     }
-    
-    pub async fn routine_privileges(&mut self, schema_names: &[String]) -> Result<Vec<RoutinePrivilege>, Error> {
-        routine_privilege::routine_privileges(&mut self.connection, schema_names).await
+
+    fn indices<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Index>, Error>> {
+        Box::pin(async move { index::indices(&mut *self.transaction().await?, schema_names).await })
+    }
+
+    fn materialized_views<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<MaterializedView>, Error>> {
+        Box::pin(async move { materialized_view::query(&mut *self.transaction().await?, schema_names).await })
+    }
+
+    fn materialized_view_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<MaterializedViewPrivilege>, Error>> {
+        Box::pin(async move { materialized_view_privilege::query(&mut *self.transaction().await?, schema_names).await })
     }
-    
-    pub async fn schemas(&mut self, schema_names: &[String]) -> Result<Vec<schema::Schema>, Error> {
-        schema::query(&mut self.connection, &schema_names).await
+
+    fn routines<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Routine>, Error>> {
+        Box::pin(async move { routine::routines(&mut *self.transaction().await?, schema_names).await })
     }
 
-    pub async fn sequences(&mut self, schema_names: &[String]) -> Result<Vec<sequence::Sequence>, Error> {
-        sequence::query(&mut self.connection, &schema_names[..]).await
+    fn routine_parameters<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<RoutineParameter>, Error>> {
+        Box::pin(async move { routine_parameters::routine_parameters(&mut *self.transaction().await?, schema_names).await })
     }
 
-    pub async fn tables(&mut self, schema_names: &[String]) -> Result<Vec<table::Table>, Error> {
-        table::query(&mut self.connection, schema_names).await
+    fn routine_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<RoutinePrivilege>, Error>> {
+        Box::pin(async move { routine_privilege::routine_privileges(&mut *self.transaction().await?, schema_names).await })
     }
 
-    pub async fn table_constraints(&mut self, schema_names: &[String]) -> Result<Vec<table_constraint::TableConstraint>, Error> {
-        table_constraint::query(&mut self.connection, schema_names).await
+    fn schemas<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Schema>, Error>> {
+        Box::pin(async move { schema::query(&mut *self.transaction().await?, schema_names).await })
     }
-    
-    pub async fn table_privileges(&mut self, schema_names: &[String]) -> Result<Vec<TablePrivilege>, Error> {
-        table_privilege::query(&mut self.connection, schema_names).await
+
+    fn sequences<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Sequence>, Error>> {
+        Box::pin(async move { sequence::query(&mut *self.transaction().await?, schema_names).await })
     }
-    
-    pub async fn table_triggers(&mut self, schema_names: &[String]) -> Result<Vec<TableTrigger>, Error> {
-        table_trigger::query(&mut self.connection, schema_names).await
+
+    fn tables<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Table>, Error>> {
+        Box::pin(async move { table::query(&mut *self.transaction().await?, schema_names).await })
+    }
+
+    fn table_constraints<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<TableConstraint>, Error>> {
+        Box::pin(async move { table_constraint::query(&mut *self.transaction().await?, schema_names).await })
+    }
+
+    fn table_policies<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<TablePolicy>, Error>> {
+        Box::pin(async move { table_policy::query(&mut *self.transaction().await?, schema_names).await })
+    }
+
+    fn table_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<TablePrivilege>, Error>> {
+        Box::pin(async move { table_privilege::query(&mut *self.transaction().await?, schema_names).await })
+    }
+
+    fn table_triggers<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<TableTrigger>, Error>> {
+        Box::pin(async move { table_trigger::query(&mut *self.transaction().await?, schema_names).await })
+    }
+
+    fn user_types<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<UserType>, Error>> {
+        Box::pin(async move { user_type::query(&mut *self.transaction().await?, schema_names).await })
+    }
+
+    fn user_type_attributes<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<UserTypeAttribute>, Error>> {
+        Box::pin(async move { user_type_attribute::query(&mut *self.transaction().await?, schema_names).await })
+    }
+
+    fn user_type_constraints<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<UserTypeConstraint>, Error>> {
+        Box::pin(async move { user_type_constraint::query(&mut *self.transaction().await?, schema_names).await })
+    }
+
+    fn user_type_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<UserTypePrivilege>, Error>> {
+        Box::pin(async move { user_type_privilege::query(&mut *self.transaction().await?, schema_names).await })
     }
 
-    pub async fn views(&mut self, schema_names: &[String]) -> Result<Vec<View>, Error> {
-        view::query(&mut self.connection, schema_names).await
+    fn views<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<View>, Error>> {
+        Box::pin(async move { view::query(&mut *self.transaction().await?, schema_names).await })
     }
 }
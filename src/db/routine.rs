@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use sqlx::{Error, FromRow, PgConnection};
 
 pub const QUERY: &str = r#"
@@ -49,21 +50,41 @@ SELECT
     r.is_user_defined_cast,
     r.is_implicitly_invocable,
     r.security_type,
-    r.is_udt_dependent
+    r.is_udt_dependent,
+    CASE WHEN p.prokind IN ('f', 'p') THEN pg_catalog.pg_get_functiondef(p.oid) END AS definition,
+    CASE p.provolatile WHEN 'i' THEN 'IMMUTABLE' WHEN 's' THEN 'STABLE' WHEN 'v' THEN 'VOLATILE' END AS volatility,
+    CASE WHEN p.proisstrict THEN 'YES' ELSE 'NO' END AS is_strict,
+    CASE p.proparallel WHEN 's' THEN 'SAFE' WHEN 'r' THEN 'RESTRICTED' WHEN 'u' THEN 'UNSAFE' END AS parallel_safety,
+    p.procost AS estimated_cost,
+    p.prorows AS estimated_rows,
+    CASE WHEN p.proleakproof THEN 'YES' ELSE 'NO' END AS is_leakproof,
+    CASE WHEN p.prosecdef THEN 'YES' ELSE 'NO' END AS is_security_definer,
+    pg_catalog.array_to_string(p.proconfig, '; ') AS configuration
 FROM
     information_schema.routines r
+    LEFT JOIN pg_catalog.pg_proc p ON r.specific_name = p.proname || '_' || p.oid
 WHERE
-    r.routine_schema = ANY($1)
+    r.routine_schema = ANY($1) AND
+    -- range constructors belong to their type
+    NOT EXISTS (
+        SELECT 1
+        FROM pg_catalog.pg_depend d
+        WHERE
+            d.classid = 'pg_catalog.pg_proc'::regclass AND
+            d.objid = p.oid AND
+            d.refclassid = 'pg_catalog.pg_type'::regclass AND
+            d.deptype = 'i')
 ORDER BY
     r.routine_name;"#;
 
+#[allow(clippy::redundant_slicing)]
 pub async fn routines(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<Routine>, Error> {
     sqlx::query_as(QUERY)
         .bind(&schema_names[..])
         .fetch_all(connection).await
 }
 
-#[derive(Debug, Clone, PartialEq, FromRow)]
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
 pub struct Routine {
     pub specific_catalog: String,
     pub specific_schema: String,
@@ -113,5 +134,14 @@ pub struct Routine {
     pub is_implicitly_invocable: Option<String>,
     pub security_type: String,
     pub is_udt_dependent: String,
+    pub definition: Option<String>,
+    pub volatility: Option<String>,
+    pub is_strict: Option<String>,
+    pub parallel_safety: Option<String>,
+    pub estimated_cost: Option<f32>,
+    pub estimated_rows: Option<f32>,
+    pub is_leakproof: Option<String>,
+    pub is_security_definer: Option<String>,
+    pub configuration: Option<String>,
 }
 
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use sqlx::{Error, FromRow, PgConnection};
 
 pub const QUERY: &str = r#"
@@ -38,13 +39,14 @@ FROM information_schema.parameters p
 WHERE
     p.specific_schema = ANY($1);"#;
 
+#[allow(clippy::redundant_slicing)]
 pub async fn routine_parameters(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<RoutineParameter>, Error> {
     sqlx::query_as(QUERY)
         .bind(&schema_names[..])
         .fetch_all(connection).await
 }
 
-#[derive(Debug, Clone, PartialEq, FromRow)]
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
 pub struct RoutineParameter {
     pub specific_catalog: String,
     pub specific_schema: String,
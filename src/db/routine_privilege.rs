@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use sqlx::{Error, FromRow, PgConnection};
 use crate::db::privilege::Privilege;
 
@@ -21,13 +22,14 @@ WHERE
 ORDER BY
     privilege_type;"#;
 
+#[allow(clippy::redundant_slicing)]
 pub async fn routine_privileges(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<RoutinePrivilege>, Error> {
     sqlx::query_as(QUERY)
         .bind(&schema_names[..])
         .fetch_all(connection).await
 }
 
-#[derive(Debug, Clone, PartialEq, FromRow)]
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
 pub struct RoutinePrivilege {
     pub grantor: String,
     pub grantee: String,
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use sqlx::{Error, FromRow, PgConnection};
 
 const QUERY: &str = r#"
@@ -15,13 +16,14 @@ WHERE
 ORDER BY
     schema_name;"#;
 
+#[allow(clippy::redundant_slicing)]
 pub async fn query(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<Schema>, Error> {
     sqlx::query_as(QUERY)
         .bind(&schema_names[..])
         .fetch_all(connection).await
 }
 
-#[derive(Debug, Clone, PartialEq, FromRow)]
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
 pub struct Schema {
     pub schema_name: String,
     pub schema_owner: String,
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use sqlx::{Error, FromRow, PgConnection};
 
 const QUERY : &str = r#"
@@ -23,13 +24,14 @@ ORDER BY
     sequence_schema,
     sequence_name;"#;
 
+#[allow(clippy::redundant_slicing)]
 pub async fn query(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<Sequence>, Error> {
     sqlx::query_as(QUERY)
         .bind(&schema_names[..])
         .fetch_all(connection).await
 }
 
-#[derive(Debug, Clone, PartialEq, FromRow)]
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
 pub struct Sequence {
     pub sequence_catalog: String,
     pub sequence_schema: String,
@@ -0,0 +1,115 @@
+use std::fs::File;
+use std::io;
+use std::io::{BufReader, BufWriter, ErrorKind, Write};
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use sqlx::Error;
+use crate::db::catalog::Catalog;
+use crate::db::source::SchemaSource;
+
+pub const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub format_version: u32,
+    #[serde(flatten)]
+    pub catalog: Catalog,
+}
+
+impl Snapshot {
+    pub async fn capture(source: &dyn SchemaSource, schema_names: &[String]) -> Result<Snapshot, Error> {
+        Ok(Snapshot {
+            format_version: FORMAT_VERSION,
+            catalog: Catalog::capture(source, schema_names).await?,
+        })
+    }
+
+    pub fn read(path: &Path) -> io::Result<Snapshot> {
+        let file = File::open(path).map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+        let snapshot: Snapshot = serde_json::from_reader(BufReader::new(file))?;
+
+        if snapshot.format_version != FORMAT_VERSION {
+            let message = format!("{}: unsupported snapshot format version {}, expected {}", path.display(), snapshot.format_version, FORMAT_VERSION);
+            return Err(io::Error::new(ErrorKind::InvalidData, message))
+        }
+
+        Ok(snapshot)
+    }
+
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        serde_json::to_writer_pretty(&mut writer, self)?;
+        writeln!(writer)?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::schema::Schema;
+
+    fn snapshot() -> Snapshot {
+        Snapshot {
+            format_version: FORMAT_VERSION,
+            catalog: Catalog {
+                schema_names: vec![String::from("public")],
+                schemas: vec![Schema {
+                    schema_name: String::from("public"),
+                    schema_owner: String::from("postgres"),
+                    default_character_set_catalog: None,
+                    default_character_set_schema: None,
+                    default_character_set_name: None,
+                    sql_path: None,
+                }],
+                ..Catalog::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_write_read() {
+        let path = std::env::temp_dir().join(format!("lbdt-snapshot-{}.json", std::process::id()));
+        let snapshot = snapshot();
+
+        snapshot.write(&path).unwrap();
+        let read = Snapshot::read(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(snapshot, read.unwrap());
+    }
+
+    #[test]
+    fn test_read_version_1() {
+        let path = std::env::temp_dir().join(format!("lbdt-snapshot-v1-{}.json", std::process::id()));
+        let fields = [
+            "columns", "column_privileges", "extensions", "extension_members", "indices", "materialized_views",
+            "materialized_view_privileges", "routines", "routine_parameters", "routine_privileges", "sequences", "tables",
+            "table_constraints", "table_policies", "table_privileges", "table_triggers", "user_types",
+            "user_type_attributes", "user_type_constraints", "user_type_privileges", "views",
+        ];
+        let empty_fields: Vec<String> = fields.iter().map(|f| format!("\"{}\": []", f)).collect();
+        let json = format!(
+            r#"{{"format_version": 1, "schema_names": ["public"], "schemas": [{{"schema_name": "public", "schema_owner": "postgres"}}], {}}}"#,
+            empty_fields.join(", "));
+
+        std::fs::write(&path, json).unwrap();
+        let read = Snapshot::read(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(snapshot(), read.unwrap());
+    }
+
+    #[test]
+    fn test_read_unsupported_version() {
+        let path = std::env::temp_dir().join(format!("lbdt-snapshot-version-{}.json", std::process::id()));
+        let snapshot = Snapshot { format_version: FORMAT_VERSION + 1, ..snapshot() };
+
+        snapshot.write(&path).unwrap();
+        let read = Snapshot::read(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(ErrorKind::InvalidData, read.unwrap_err().kind());
+    }
+}
@@ -0,0 +1,74 @@
+use futures::future::BoxFuture;
+use sqlx::Error;
+use crate::db::column::Column;
+use crate::db::column_privilege::ColumnPrivilege;
+use crate::db::extension::Extension;
+use crate::db::extension_member::ExtensionMember;
+use crate::db::index::Index;
+use crate::db::materialized_view::MaterializedView;
+use crate::db::materialized_view_privilege::MaterializedViewPrivilege;
+use crate::db::routine::Routine;
+use crate::db::routine_parameters::RoutineParameter;
+use crate::db::routine_privilege::RoutinePrivilege;
+use crate::db::schema::Schema;
+use crate::db::sequence::Sequence;
+use crate::db::table::Table;
+use crate::db::table_constraint::TableConstraint;
+use crate::db::table_policy::TablePolicy;
+use crate::db::table_privilege::TablePrivilege;
+use crate::db::table_trigger::TableTrigger;
+use crate::db::user_type::UserType;
+use crate::db::user_type_attribute::UserTypeAttribute;
+use crate::db::user_type_constraint::UserTypeConstraint;
+use crate::db::user_type_privilege::UserTypePrivilege;
+use crate::db::view::View;
+
+/// The catalog queries a [`crate::Comparer`] runs against each side of a comparison.
+///
+/// Every query returns the rows belonging to any of `schema_names`, except for [`SchemaSource::extensions`], which
+/// belong to the database as a whole. Queries may run concurrently.
+pub trait SchemaSource: Send + Sync {
+    fn columns<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Column>, Error>>;
+
+    fn column_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<ColumnPrivilege>, Error>>;
+
+    fn extensions(&self) -> BoxFuture<'_, Result<Vec<Extension>, Error>>;
+
+    fn extension_members<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<ExtensionMember>, Error>>;
+
+    fn indices<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Index>, Error>>;
+
+    fn materialized_views<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<MaterializedView>, Error>>;
+
+    fn materialized_view_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<MaterializedViewPrivilege>, Error>>;
+
+    fn routines<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Routine>, Error>>;
+
+    fn routine_parameters<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<RoutineParameter>, Error>>;
+
+    fn routine_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<RoutinePrivilege>, Error>>;
+
+    fn schemas<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Schema>, Error>>;
+
+    fn sequences<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Sequence>, Error>>;
+
+    fn tables<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<Table>, Error>>;
+
+    fn table_constraints<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<TableConstraint>, Error>>;
+
+    fn table_policies<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<TablePolicy>, Error>>;
+
+    fn table_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<TablePrivilege>, Error>>;
+
+    fn table_triggers<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<TableTrigger>, Error>>;
+
+    fn user_types<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<UserType>, Error>>;
+
+    fn user_type_attributes<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<UserTypeAttribute>, Error>>;
+
+    fn user_type_constraints<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<UserTypeConstraint>, Error>>;
+
+    fn user_type_privileges<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<UserTypePrivilege>, Error>>;
+
+    fn views<'a>(&'a self, schema_names: &'a [String]) -> BoxFuture<'a, Result<Vec<View>, Error>>;
+}
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use sqlx::{Error, FromRow, PgConnection};
 
 const QUERY : &str = r#"
@@ -13,9 +14,27 @@ SELECT
     user_defined_type_name,
     is_insertable_into,
     is_typed,
-    commit_action
+    commit_action,
+    CASE WHEN c.relrowsecurity THEN 'YES' ELSE 'NO' END AS row_security,
+    CASE WHEN c.relforcerowsecurity THEN 'YES' ELSE 'NO' END AS force_row_security,
+    CASE pt.partstrat WHEN 'r' THEN 'RANGE' WHEN 'l' THEN 'LIST' WHEN 'h' THEN 'HASH' END AS partition_strategy,
+    regexp_replace(pg_catalog.pg_get_partkeydef(c.oid), '^\w+ ', '') AS partition_key,
+    pn.nspname::text AS partition_parent_schema,
+    pc.relname::text AS partition_parent_name,
+    pg_catalog.pg_get_expr(c.relpartbound, c.oid) AS partition_bound,
+    (SELECT string_agg(format('%I.%I', ipn.nspname, ipc.relname), ', ' ORDER BY ii.inhseqno)
+     FROM pg_catalog.pg_inherits ii
+         JOIN pg_catalog.pg_class ipc ON ipc.oid = ii.inhparent
+         JOIN pg_catalog.pg_namespace ipn ON ipn.oid = ipc.relnamespace
+     WHERE ii.inhrelid = c.oid AND NOT c.relispartition) AS parents
 FROM
     information_schema.tables
+    LEFT JOIN pg_catalog.pg_namespace n ON n.nspname = table_schema
+    LEFT JOIN pg_catalog.pg_class c ON c.relnamespace = n.oid AND c.relname = table_name
+    LEFT JOIN pg_catalog.pg_partitioned_table pt ON pt.partrelid = c.oid
+    LEFT JOIN pg_catalog.pg_inherits i ON i.inhrelid = c.oid AND c.relispartition
+    LEFT JOIN pg_catalog.pg_class pc ON pc.oid = i.inhparent
+    LEFT JOIN pg_catalog.pg_namespace pn ON pn.oid = pc.relnamespace
 WHERE
     table_schema = ANY($1)
 ORDER BY
@@ -23,13 +42,14 @@ ORDER BY
     table_schema,
     table_name;"#;
 
+#[allow(clippy::redundant_slicing)]
 pub async fn query(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<Table>, Error> {
     sqlx::query_as(QUERY)
         .bind(&schema_names[..])
         .fetch_all(connection).await
 }
 
-#[derive(Debug, Clone, PartialEq, FromRow)]
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
 pub struct Table {
     pub table_catalog: String,
     pub table_schema: String,
@@ -43,4 +63,12 @@ pub struct Table {
     pub is_insertable_into: String,
     pub is_typed: String,
     pub commit_action: Option<String>,
+    pub row_security: String,
+    pub force_row_security: String,
+    pub partition_strategy: Option<String>,
+    pub partition_key: Option<String>,
+    pub partition_parent_schema: Option<String>,
+    pub partition_parent_name: Option<String>,
+    pub partition_bound: Option<String>,
+    pub parents: Option<String>,
 }
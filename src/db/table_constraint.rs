@@ -1,38 +1,66 @@
+use serde::{Deserialize, Serialize};
 use sqlx::{Error, FromRow, PgConnection};
 
 const QUERY: &str = r#"
 SELECT
-    constraint_catalog,
-    constraint_schema,
-    constraint_name,
-    constraint_type,
-    table_catalog,
-    table_schema,
-    table_name,
-    is_deferrable,
-    initially_deferred,
-    enforced,
-    nulls_distinct
+    tc.constraint_catalog,
+    tc.constraint_schema,
+    tc.constraint_name,
+    tc.constraint_type,
+    tc.table_catalog,
+    tc.table_schema,
+    tc.table_name,
+    tc.is_deferrable,
+    tc.initially_deferred,
+    tc.enforced,
+    tc.nulls_distinct,
+    pg_catalog.pg_get_constraintdef(c.oid) AS definition,
+    CASE WHEN c.contype IN ('f', 'p', 'u') THEN
+        (SELECT pg_catalog.string_agg(pg_catalog.quote_ident(a.attname), ', ' ORDER BY k.position)
+         FROM pg_catalog.unnest(c.conkey) WITH ORDINALITY AS k(attnum, position)
+         JOIN pg_catalog.pg_attribute a ON a.attrelid = c.conrelid AND a.attnum = k.attnum)
+    END AS key_columns,
+    rn.nspname AS referenced_table_schema,
+    rt.relname AS referenced_table_name,
+    (SELECT pg_catalog.string_agg(pg_catalog.quote_ident(a.attname), ', ' ORDER BY k.position)
+     FROM pg_catalog.unnest(c.confkey) WITH ORDINALITY AS k(attnum, position)
+     JOIN pg_catalog.pg_attribute a ON a.attrelid = c.confrelid AND a.attnum = k.attnum) AS referenced_columns,
+    CASE WHEN c.contype = 'f' THEN
+        CASE c.confmatchtype WHEN 'f' THEN 'FULL' WHEN 'p' THEN 'PARTIAL' ELSE 'SIMPLE' END
+    END AS match_option,
+    CASE WHEN c.contype = 'f' THEN
+        CASE c.confupdtype WHEN 'a' THEN 'NO ACTION' WHEN 'r' THEN 'RESTRICT' WHEN 'c' THEN 'CASCADE' WHEN 'n' THEN 'SET NULL' WHEN 'd' THEN 'SET DEFAULT' END
+    END AS update_rule,
+    CASE WHEN c.contype = 'f' THEN
+        CASE c.confdeltype WHEN 'a' THEN 'NO ACTION' WHEN 'r' THEN 'RESTRICT' WHEN 'c' THEN 'CASCADE' WHEN 'n' THEN 'SET NULL' WHEN 'd' THEN 'SET DEFAULT' END
+    END AS delete_rule
 FROM
-    information_schema.table_constraints
+    information_schema.table_constraints tc
+    LEFT JOIN pg_catalog.pg_namespace n ON n.nspname = tc.table_schema
+    LEFT JOIN pg_catalog.pg_class t ON t.relnamespace = n.oid AND t.relname = tc.table_name
+    LEFT JOIN pg_catalog.pg_constraint c ON c.conrelid = t.oid AND c.conname = tc.constraint_name
+    LEFT JOIN pg_catalog.pg_class rt ON rt.oid = c.confrelid
+    LEFT JOIN pg_catalog.pg_namespace rn ON rn.oid = rt.relnamespace
 WHERE
-    table_schema = ANY($1)
+    tc.table_schema = ANY($1)
   AND
-    constraint_type != 'CHECK'
+    -- NOT NULL columns are reported as CHECK constraints without a pg_constraint check of their own
+    (tc.constraint_type != 'CHECK' OR c.contype = 'c')
 ORDER BY
-    table_catalog,
-    table_schema,
-    table_name,
-    constraint_name,
-    constraint_type;"#;
+    tc.table_catalog,
+    tc.table_schema,
+    tc.table_name,
+    tc.constraint_name,
+    tc.constraint_type;"#;
 
+#[allow(clippy::redundant_slicing)]
 pub async fn query(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<TableConstraint>, Error> {
     sqlx::query_as(QUERY)
         .bind(&schema_names[..])
         .fetch_all(connection).await
 }
 
-#[derive(Debug, Clone, PartialEq, FromRow)]
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
 pub struct TableConstraint {
     pub constraint_catalog: String,
     pub constraint_schema: String,
@@ -45,4 +73,12 @@ pub struct TableConstraint {
     pub initially_deferred: String,
     pub enforced: String,
     pub nulls_distinct: Option<String>,
+    pub definition: Option<String>,
+    pub key_columns: Option<String>,
+    pub referenced_table_schema: Option<String>,
+    pub referenced_table_name: Option<String>,
+    pub referenced_columns: Option<String>,
+    pub match_option: Option<String>,
+    pub update_rule: Option<String>,
+    pub delete_rule: Option<String>,
 }
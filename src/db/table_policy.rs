@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{Error, FromRow, PgConnection};
+
+const QUERY: &str = r#"
+SELECT
+    p.schemaname AS table_schema,
+    p.tablename AS table_name,
+    p.policyname AS policy_name,
+    p.permissive,
+    (SELECT pg_catalog.string_agg(r, ', ' ORDER BY r) FROM pg_catalog.unnest(p.roles) AS r) AS roles,
+    p.cmd AS command,
+    p.qual AS using_expression,
+    p.with_check AS check_expression
+FROM
+    pg_catalog.pg_policies p
+WHERE
+    p.schemaname = ANY($1)
+ORDER BY
+    table_schema,
+    table_name,
+    policy_name;"#;
+
+pub async fn query(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<TablePolicy>, Error> {
+    sqlx::query_as(QUERY)
+        .bind(schema_names)
+        .fetch_all(connection).await
+}
+
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
+pub struct TablePolicy {
+    pub table_schema: String,
+    pub table_name: String,
+    pub policy_name: String,
+    pub permissive: String,
+    pub roles: String,
+    pub command: String,
+    pub using_expression: Option<String>,
+    pub check_expression: Option<String>,
+}
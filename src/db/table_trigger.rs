@@ -1,41 +1,47 @@
+use serde::{Deserialize, Serialize};
 use sqlx::{Error, FromRow, PgConnection};
 
 const QUERY : &str = r#"
 SELECT
-    trigger_catalog,
-    trigger_schema,
-    trigger_name,
-    event_manipulation,
-    event_object_catalog,
-    event_object_schema,
-    event_object_table,
-    action_order,
-    action_condition,
-    action_statement,
-    action_orientation,
-    action_timing,
-    action_reference_old_table,
-    action_reference_new_table,
-    action_reference_old_row,
-    action_reference_new_row
+    t.trigger_catalog,
+    t.trigger_schema,
+    t.trigger_name,
+    t.event_manipulation,
+    t.event_object_catalog,
+    t.event_object_schema,
+    t.event_object_table,
+    t.action_order,
+    t.action_condition,
+    t.action_statement,
+    t.action_orientation,
+    t.action_timing,
+    t.action_reference_old_table,
+    t.action_reference_new_table,
+    t.action_reference_old_row,
+    t.action_reference_new_row,
+    pg_catalog.pg_get_triggerdef(pt.oid) AS definition
 FROM
-    information_schema.triggers
+    information_schema.triggers t
+    LEFT JOIN pg_catalog.pg_namespace n ON n.nspname = t.event_object_schema
+    LEFT JOIN pg_catalog.pg_class c ON c.relnamespace = n.oid AND c.relname = t.event_object_table
+    LEFT JOIN pg_catalog.pg_trigger pt ON pt.tgrelid = c.oid AND pt.tgname = t.trigger_name
 WHERE
-    trigger_schema = ANY($1)
+    t.trigger_schema = ANY($1)
 ORDER BY
-    event_object_catalog,
-    event_object_schema,
-    event_object_table,
-    trigger_name,
-    event_manipulation;"#;
+    t.event_object_catalog,
+    t.event_object_schema,
+    t.event_object_table,
+    t.trigger_name,
+    t.event_manipulation;"#;
 
+#[allow(clippy::redundant_slicing)]
 pub async fn query(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<TableTrigger>, Error> {
     sqlx::query_as(QUERY)
         .bind(&schema_names[..])
         .fetch_all(connection).await
 }
 
-#[derive(Debug, Clone, PartialEq, FromRow)]
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
 pub struct TableTrigger {
     pub trigger_catalog: String,
     pub trigger_schema: String,
@@ -51,5 +57,6 @@ pub struct TableTrigger {
     pub action_timing: String,
     pub action_reference_old_table: Option<String>,
     pub action_reference_new_table: Option<String>,
+    pub definition: Option<String>,
 }
 
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{Error, FromRow, PgConnection};
+
+const QUERY: &str = r#"
+SELECT
+    n.nspname AS type_schema,
+    t.typname AS type_name,
+    CASE t.typtype
+        WHEN 'c' THEN 'COMPOSITE'
+        WHEN 'd' THEN 'DOMAIN'
+        WHEN 'e' THEN 'ENUM'
+        WHEN 'r' THEN 'RANGE'
+    END AS type_kind,
+    pg_catalog.pg_get_userbyid(t.typowner) AS type_owner,
+    (SELECT pg_catalog.string_agg(pg_catalog.quote_literal(e.enumlabel), ', ' ORDER BY e.enumsortorder)
+     FROM pg_catalog.pg_enum e
+     WHERE e.enumtypid = t.oid) AS enum_labels,
+    CASE WHEN t.typtype = 'd' THEN pg_catalog.format_type(t.typbasetype, t.typtypmod) END AS base_type,
+    t.typdefault AS default_value,
+    CASE WHEN t.typnotnull THEN 'YES' ELSE 'NO' END AS is_not_null,
+    CASE WHEN t.typtype = 'r' THEN pg_catalog.format_type(r.rngsubtype, NULL) END AS range_subtype
+FROM
+    pg_catalog.pg_type t
+    JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+    LEFT JOIN pg_catalog.pg_class c ON c.oid = t.typrelid
+    LEFT JOIN pg_catalog.pg_range r ON r.rngtypid = t.oid
+WHERE
+    n.nspname = ANY($1) AND
+    t.typtype IN ('c', 'd', 'e', 'r') AND
+    (t.typtype <> 'c' OR c.relkind = 'c')
+ORDER BY
+    type_schema,
+    type_name;"#;
+
+pub async fn query(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<UserType>, Error> {
+    sqlx::query_as(QUERY)
+        .bind(schema_names)
+        .fetch_all(connection).await
+}
+
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
+pub struct UserType {
+    pub type_schema: String,
+    pub type_name: String,
+    pub type_kind: String,
+    pub type_owner: String,
+    pub enum_labels: Option<String>,
+    pub base_type: Option<String>,
+    pub default_value: Option<String>,
+    pub is_not_null: String,
+    pub range_subtype: Option<String>,
+}
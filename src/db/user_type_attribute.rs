@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{Error, FromRow, PgConnection};
+
+const QUERY: &str = r#"
+SELECT
+    n.nspname AS type_schema,
+    t.typname AS type_name,
+    a.attname AS attribute_name,
+    a.attnum::int4 AS ordinal_position,
+    pg_catalog.format_type(a.atttypid, a.atttypmod) AS data_type,
+    co.collname AS collation_name
+FROM
+    pg_catalog.pg_type t
+    JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+    JOIN pg_catalog.pg_class c ON c.oid = t.typrelid
+    JOIN pg_catalog.pg_attribute a ON a.attrelid = c.oid
+    LEFT JOIN pg_catalog.pg_collation co ON co.oid = a.attcollation
+WHERE
+    n.nspname = ANY($1) AND
+    t.typtype = 'c' AND
+    c.relkind = 'c' AND
+    a.attnum > 0 AND
+    NOT a.attisdropped
+ORDER BY
+    type_schema,
+    type_name,
+    ordinal_position;"#;
+
+pub async fn query(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<UserTypeAttribute>, Error> {
+    sqlx::query_as(QUERY)
+        .bind(schema_names)
+        .fetch_all(connection).await
+}
+
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
+pub struct UserTypeAttribute {
+    pub type_schema: String,
+    pub type_name: String,
+    pub attribute_name: String,
+    pub ordinal_position: i32,
+    pub data_type: String,
+    pub collation_name: Option<String>,
+}
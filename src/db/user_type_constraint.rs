@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{Error, FromRow, PgConnection};
+
+const QUERY: &str = r#"
+SELECT
+    n.nspname AS type_schema,
+    t.typname AS type_name,
+    c.conname AS constraint_name,
+    pg_catalog.pg_get_constraintdef(c.oid) AS definition,
+    CASE WHEN c.convalidated THEN 'YES' ELSE 'NO' END AS is_validated
+FROM
+    pg_catalog.pg_constraint c
+    JOIN pg_catalog.pg_type t ON t.oid = c.contypid
+    JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+WHERE
+    n.nspname = ANY($1)
+ORDER BY
+    type_schema,
+    type_name,
+    constraint_name;"#;
+
+pub async fn query(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<UserTypeConstraint>, Error> {
+    sqlx::query_as(QUERY)
+        .bind(schema_names)
+        .fetch_all(connection).await
+}
+
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
+pub struct UserTypeConstraint {
+    pub type_schema: String,
+    pub type_name: String,
+    pub constraint_name: String,
+    pub definition: String,
+    pub is_validated: String,
+}
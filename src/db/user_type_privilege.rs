@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{Error, FromRow, PgConnection};
+use crate::db::privilege::Privilege;
+
+const QUERY: &str = r#"
+SELECT
+    pg_catalog.pg_get_userbyid(a.grantor) AS grantor,
+    CASE WHEN a.grantee = 0 THEN 'PUBLIC' ELSE pg_catalog.pg_get_userbyid(a.grantee) END AS grantee,
+    n.nspname AS type_schema,
+    t.typname AS type_name,
+    a.privilege_type,
+    CASE WHEN a.is_grantable THEN 'YES' ELSE 'NO' END AS is_grantable
+FROM
+    pg_catalog.pg_type t
+    JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+    LEFT JOIN pg_catalog.pg_class c ON c.oid = t.typrelid
+    CROSS JOIN LATERAL pg_catalog.aclexplode(COALESCE(t.typacl, pg_catalog.acldefault('T', t.typowner))) a
+WHERE
+    n.nspname = ANY($1) AND
+    t.typtype IN ('c', 'd', 'e', 'r') AND
+    (t.typtype <> 'c' OR c.relkind = 'c')
+ORDER BY
+    type_schema,
+    type_name,
+    grantor,
+    grantee,
+    privilege_type;"#;
+
+pub async fn query(connection: &mut PgConnection, schema_names: &[String]) -> Result<Vec<UserTypePrivilege>, Error> {
+    sqlx::query_as(QUERY)
+        .bind(schema_names)
+        .fetch_all(connection).await
+}
+
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize)]
+pub struct UserTypePrivilege {
+    pub grantor: String,
+    pub grantee: String,
+    pub type_schema: String,
+    pub type_name: String,
+    pub privilege_type: String,
+    pub is_grantable: String,
+}
+
+impl Privilege for &UserTypePrivilege {
+    fn grantor(&self) -> &str {
+        &self.grantor
+    }
+
+    fn grantee(&self) -> &str {
+        &self.grantee
+    }
+
+    fn privilege_type(&self) -> &str {
+        &self.privilege_type
+    }
+}
@@ -0,0 +1,17 @@
+//! Little Bobby Diff Tool: compares database schemas.
+//!
+//! Open a [`SchemaSource`] for each side (a live [`Database`], or a
+//! [`db::catalog::Catalog`] loaded from a snapshot or built in memory), hand
+//! both to a [`Comparer`] and walk the resulting
+//! [`compare::report::database::DatabaseComparison`], which holds a [`Report`]
+//! of extensions and one of [`compare::report::schema::SchemaComparison`]s.
+
+pub mod compare;
+pub mod db;
+pub mod migration;
+mod string;
+
+pub use crate::compare::Comparer;
+pub use crate::compare::report::{HasChanges, Report};
+pub use crate::db::Database;
+pub use crate::db::source::SchemaSource;
@@ -1,7 +1,4 @@
-mod db;
-mod compare;
 mod cli;
-mod string;
 
 use std::process;
 use crate::cli::CLI;
@@ -12,10 +9,10 @@ async fn main() {
     let result = cli.run().await;
 
     match result {
-         Ok(count) => process::exit(count),
-         Err(err) => {
-             eprintln!("{}", err);
-             process::exit(-1);
+         Ok(code) => process::exit(code),
+         Err(failure) => {
+             eprintln!("{}", failure);
+             process::exit(failure.exit_code());
          },
      }
 }
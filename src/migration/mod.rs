@@ -0,0 +1,880 @@
+use std::fmt::{Display, Formatter};
+
+use crate::compare::report::{HasChanges, Report};
+use crate::compare::report::column::ColumnComparison;
+use crate::compare::report::column::ColumnComparison::{ColumnAdded, ColumnMaintained, ColumnRemoved};
+use crate::compare::report::database::DatabaseComparison;
+use crate::compare::report::extension::ExtensionComparison;
+use crate::compare::report::extension::ExtensionComparison::{ExtensionAdded, ExtensionMaintained, ExtensionRemoved};
+use crate::compare::report::index::IndexComparison;
+use crate::compare::report::index::IndexComparison::{IndexAdded, IndexMaintained, IndexRemoved};
+use crate::compare::report::partition::PartitionComparison;
+use crate::compare::report::partition::PartitionComparison::{PartitionAdded, PartitionMaintained, PartitionRemoved};
+use crate::compare::report::policy::PolicyComparison;
+use crate::compare::report::policy::PolicyComparison::{PolicyAdded, PolicyMaintained, PolicyRemoved};
+use crate::compare::report::materialized_view::MaterializedViewComparison;
+use crate::compare::report::materialized_view::MaterializedViewComparison::{MaterializedViewAdded, MaterializedViewMaintained, MaterializedViewRemoved};
+use crate::compare::report::privilege::PrivilegeComparison;
+use crate::compare::report::privilege::PrivilegeComparison::{PrivilegeAdded, PrivilegeMaintained, PrivilegeRemoved};
+use crate::compare::report::property::PropertyComparison;
+use crate::compare::report::property::PropertyComparison::{PropertyChanged, PropertyUnchanged};
+use crate::compare::report::routine::RoutineComparison;
+use crate::compare::report::routine::RoutineComparison::{RoutineAdded, RoutineMaintained, RoutineRemoved};
+use crate::compare::report::schema::SchemaComparison;
+use crate::compare::report::schema::SchemaComparison::{SchemaAdded, SchemaMaintained, SchemaMissing, SchemaRemoved};
+use crate::compare::report::sequence::SequenceComparison;
+use crate::compare::report::sequence::SequenceComparison::{SequenceAdded, SequenceMaintained, SequenceRemoved};
+use crate::compare::report::table::TableComparison;
+use crate::compare::report::table::TableComparison::{TableAdded, TableMaintained, TableRemoved};
+use crate::compare::report::table_constraint::TableConstraintComparison;
+use crate::compare::report::table_constraint::TableConstraintComparison::{ConstraintAdded, ConstraintMaintained, ConstraintRemoved};
+use crate::compare::report::table_trigger::TableTriggerComparison;
+use crate::compare::report::table_trigger::TableTriggerComparison::{TriggerAdded, TriggerMaintained, TriggerRemoved};
+use crate::compare::report::user_type::UserTypeComparison;
+use crate::compare::report::user_type::UserTypeComparison::{UserTypeAdded, UserTypeMaintained, UserTypeRemoved};
+use crate::compare::report::user_type_attribute::UserTypeAttributeComparison;
+use crate::compare::report::user_type_attribute::UserTypeAttributeComparison::{AttributeAdded, AttributeMaintained, AttributeRemoved};
+use crate::compare::report::view::ViewComparison;
+use crate::compare::report::view::ViewComparison::{ViewAdded, ViewMaintained, ViewRemoved};
+use crate::string::{qualified_name, quote_identifier, quote_literal};
+
+const COLUMN_TYPE_PROPERTIES: &[&str] = &[
+    "data_type", "character_maximum_length", "character_octet_length", "numeric_precision", "numeric_precision_radix",
+    "numeric_scale", "datetime_precision", "interval_type", "interval_precision", "udt_catalog", "udt_schema", "udt_name",
+    "dtd_identifier",
+];
+
+const SEQUENCE_TYPE_PROPERTIES: &[&str] = &[
+    "numeric_precision", "numeric_precision_radix", "numeric_scale",
+];
+
+const PARTITION_ATTACHMENT_PROPERTIES: &[&str] = &[
+    "partition_parent_schema", "partition_parent_name", "partition_bound",
+];
+
+const VIEW_DERIVED_PROPERTIES: &[&str] = &[
+    "is_updatable", "is_insertable_into", "is_trigger_updatable", "is_trigger_deletable", "is_trigger_insertable_into",
+];
+
+/// SQL statements that bring the left database in line with the right, derived from a comparison report.
+///
+/// Changes that cannot be expressed as DDL are emitted as comments so they are not silently lost.
+pub struct Migration {
+    pub statements: Vec<String>,
+}
+
+enum PrivilegeTarget<'a> {
+    Table { table_name: &'a str },
+    Column { table_name: &'a str, column_name: &'a str },
+    Routine { routine_identity: &'a str },
+    Type { type_name: &'a str },
+}
+
+impl Migration {
+    pub fn new(report: &DatabaseComparison) -> Migration {
+        let mut migration = Migration { statements: Vec::new() };
+
+        // schema objects may depend on extensions
+        migration.add_extension_statements(&report.extensions, false);
+
+        for schema in &report.schemas.entries {
+            migration.add_schema_statements(schema);
+        }
+
+        migration.add_extension_statements(&report.extensions, true);
+
+        migration
+    }
+
+    fn add_extension_statements(&mut self, report: &Report<ExtensionComparison>, removals: bool) {
+        for extension in &report.entries {
+            match extension {
+                ExtensionAdded { definition, .. } if !removals => self.statement(definition.clone()),
+                ExtensionRemoved { extension_name } if removals => {
+                    self.statement(format!("DROP EXTENSION {}", quote_identifier(extension_name)));
+                },
+                ExtensionMaintained { extension_name, properties } if !removals => {
+                    let name = quote_identifier(extension_name);
+                    let version_changed = changed_properties(properties).any(|(p, _, _)| p == "extension_version");
+
+                    for (property_name, _, right_value) in changed_properties(properties) {
+                        match (property_name, right_value) {
+                            ("extension_version", Some(version)) => self.statement(format!("ALTER EXTENSION {} UPDATE TO {}", name, quote_literal(version))),
+                            ("extension_schema", Some(schema)) => self.statement(format!("ALTER EXTENSION {} SET SCHEMA {}", name, quote_identifier(schema))),
+                            // relocatability is part of the extension version
+                            ("is_relocatable", _) if version_changed => (),
+                            _ => self.unsupported_property(&format!("extension {}", name), properties, property_name),
+                        }
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn add_schema_statements(&mut self, schema: &SchemaComparison) {
+        match schema {
+            SchemaMissing { .. } => (),
+            SchemaAdded { schema_name } => {
+                self.statement(format!("CREATE SCHEMA {}", quote_identifier(schema_name)));
+                self.comment(format!("objects in schema '{}' must be created separately", schema_name));
+            },
+            SchemaRemoved { schema_name } => {
+                self.statement(format!("DROP SCHEMA {} CASCADE", quote_identifier(schema_name)));
+            },
+            SchemaMaintained { schema_name, properties, materialized_views, routines, sequences, tables, types, views } => {
+                if !schema.has_changes() {
+                    return
+                }
+
+                for (property_name, _, right_value) in changed_properties(properties) {
+                    match (property_name, right_value) {
+                        ("schema_owner", Some(owner)) => self.statement(format!("ALTER SCHEMA {} OWNER TO {}", quote_identifier(schema_name), quote_identifier(owner))),
+                        _ => self.unsupported_property(&format!("schema '{}'", schema_name), properties, property_name),
+                    }
+                }
+
+                self.add_user_type_statements(schema_name, types, false);
+                self.add_sequence_statements(schema_name, sequences, false);
+                self.add_routine_statements(schema_name, routines, false);
+                // views are dropped before and created after table changes, as they may depend on them
+                self.add_materialized_view_statements(schema_name, materialized_views, true);
+                self.add_view_statements(schema_name, views, true);
+                self.add_table_statements(schema_name, tables);
+                self.add_view_statements(schema_name, views, false);
+                self.add_materialized_view_statements(schema_name, materialized_views, false);
+                self.add_routine_statements(schema_name, routines, true);
+                self.add_sequence_statements(schema_name, sequences, true);
+                self.add_user_type_statements(schema_name, types, true);
+            },
+        }
+    }
+
+    fn add_user_type_statements(&mut self, schema_name: &str, report: &Report<UserTypeComparison>, removals: bool) {
+        for user_type in &report.entries {
+            match user_type {
+                UserTypeAdded { definition, .. } if !removals => self.statement(definition.clone()),
+                UserTypeRemoved { type_name, type_kind } if removals => {
+                    self.statement(format!("DROP {} {}", type_keyword(type_kind), qualified_name(schema_name, type_name)));
+                },
+                UserTypeMaintained { type_name, attributes, constraints, privileges, properties } if !removals => {
+                    let name = qualified_name(schema_name, type_name);
+                    let keyword = type_keyword(property_value(properties, "type_kind").unwrap_or_default());
+
+                    if changed_properties(properties).any(|(p, _, _)| p == "type_kind") {
+                        self.unsupported_property(&format!("type {}", name), properties, "type_kind");
+                        continue
+                    }
+
+                    for (property_name, left_value, right_value) in changed_properties(properties) {
+                        match (property_name, right_value) {
+                            ("type_owner", Some(owner)) => self.statement(format!("ALTER {} {} OWNER TO {}", keyword, name, quote_identifier(owner))),
+                            ("enum_labels", Some(labels)) => match added_enum_labels(left_value.unwrap_or_default(), labels) {
+                                Some(clauses) => for clause in clauses {
+                                    self.statement(format!("ALTER TYPE {} ADD VALUE {}", name, clause));
+                                },
+                                None => self.unsupported_property(&format!("type {}", name), properties, property_name),
+                            },
+                            ("default_value", None) => self.statement(format!("ALTER DOMAIN {} DROP DEFAULT", name)),
+                            ("default_value", Some(default)) => self.statement(format!("ALTER DOMAIN {} SET DEFAULT {}", name, default)),
+                            ("is_not_null", Some("YES")) => self.statement(format!("ALTER DOMAIN {} SET NOT NULL", name)),
+                            ("is_not_null", _) => self.statement(format!("ALTER DOMAIN {} DROP NOT NULL", name)),
+                            _ => self.unsupported_property(&format!("type {}", name), properties, property_name),
+                        }
+                    }
+
+                    self.add_user_type_attribute_statements(&name, attributes);
+                    self.add_domain_constraint_statements(&name, constraints, true);
+                    self.add_domain_constraint_statements(&name, constraints, false);
+                    self.add_privilege_statements(PrivilegeTarget::Type { type_name: &name }, privileges);
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn add_user_type_attribute_statements(&mut self, type_name: &str, report: &Report<UserTypeAttributeComparison>) {
+        for attribute in &report.entries {
+            match attribute {
+                AttributeRemoved { attribute_name } => {
+                    self.statement(format!("ALTER TYPE {} DROP ATTRIBUTE {}", type_name, quote_identifier(attribute_name)));
+                },
+                AttributeAdded { definition, .. } => {
+                    self.statement(format!("ALTER TYPE {} ADD ATTRIBUTE {}", type_name, definition));
+                },
+                AttributeMaintained { attribute_name, properties } => {
+                    let attribute = quote_identifier(attribute_name);
+
+                    if changed_properties(properties).any(|(p, _, _)| p == "data_type" || p == "collation_name") {
+                        let mut statement = format!("ALTER TYPE {} ALTER ATTRIBUTE {} TYPE {}", type_name, attribute, property_value(properties, "data_type").unwrap_or_default());
+
+                        if let Some(collation_name) = property_value(properties, "collation_name").filter(|c| *c != "default") {
+                            statement.push_str(&format!(" COLLATE {}", quote_identifier(collation_name)));
+                        }
+
+                        self.statement(statement);
+                    }
+
+                    for (property_name, _, _) in changed_properties(properties) {
+                        if property_name != "data_type" && property_name != "collation_name" {
+                            self.unsupported_property(&format!("attribute {}.{}", type_name, attribute), properties, property_name);
+                        }
+                    }
+                },
+            }
+        }
+    }
+
+    fn add_domain_constraint_statements(&mut self, domain_name: &str, report: &Report<TableConstraintComparison>, removals: bool) {
+        for constraint in &report.entries {
+            match constraint {
+                ConstraintRemoved { constraint_name } if removals => {
+                    self.statement(format!("ALTER DOMAIN {} DROP CONSTRAINT {}", domain_name, quote_identifier(constraint_name)));
+                },
+                ConstraintAdded { constraint_name, definition: Some(definition) } if !removals => {
+                    self.statement(format!("ALTER DOMAIN {} ADD CONSTRAINT {} {}", domain_name, quote_identifier(constraint_name), definition));
+                },
+                ConstraintMaintained { constraint_name, properties } if constraint.has_changes() => {
+                    if removals {
+                        self.statement(format!("ALTER DOMAIN {} DROP CONSTRAINT {}", domain_name, quote_identifier(constraint_name)));
+                    } else if let Some(definition) = property_value(properties, "definition") {
+                        self.statement(format!("ALTER DOMAIN {} ADD CONSTRAINT {} {}", domain_name, quote_identifier(constraint_name), definition));
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn add_sequence_statements(&mut self, schema_name: &str, report: &Report<SequenceComparison>, removals: bool) {
+        for sequence in &report.entries {
+            match sequence {
+                SequenceAdded { definition, .. } if !removals => self.statement(definition.clone()),
+                SequenceRemoved { sequence_name } if removals => {
+                    self.statement(format!("DROP SEQUENCE {}", qualified_name(schema_name, sequence_name)));
+                },
+                SequenceMaintained { sequence_name, properties } if !removals => {
+                    let name = qualified_name(schema_name, sequence_name);
+                    let mut clauses = Vec::new();
+                    let data_type_changed = changed_properties(properties).any(|(p, _, _)| p == "data_type");
+
+                    for (property_name, _, right_value) in changed_properties(properties) {
+                        match (property_name, right_value) {
+                            ("data_type", Some(data_type)) => clauses.push(format!("AS {}", data_type)),
+                            ("increment", Some(increment)) => clauses.push(format!("INCREMENT BY {}", increment)),
+                            ("minimum_value", Some(minimum)) => clauses.push(format!("MINVALUE {}", minimum)),
+                            ("maximum_value", Some(maximum)) => clauses.push(format!("MAXVALUE {}", maximum)),
+                            ("start_value", Some(start)) => clauses.push(format!("START WITH {}", start)),
+                            ("cycle_option", cycle) => clauses.push(String::from(if cycle == Some("YES") { "CYCLE" } else { "NO CYCLE" })),
+                            _ if data_type_changed && SEQUENCE_TYPE_PROPERTIES.contains(&property_name) => (),
+                            _ => self.unsupported_property(&format!("sequence {}", name), properties, property_name),
+                        }
+                    }
+
+                    if !clauses.is_empty() {
+                        self.statement(format!("ALTER SEQUENCE {} {}", name, clauses.join(" ")));
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn add_routine_statements(&mut self, schema_name: &str, report: &Report<RoutineComparison>, removals: bool) {
+        for routine in &report.entries {
+            match routine {
+                RoutineAdded { routine_signature, definition } if !removals => {
+                    self.definition(&format!("routine {}.{}", schema_name, routine_signature), definition);
+                },
+                RoutineRemoved { routine_signature } if removals => {
+                    self.statement(format!("DROP ROUTINE {}", routine_identity(schema_name, routine_signature)));
+                },
+                RoutineMaintained { routine_signature, definition, properties, privileges } if !removals => {
+                    if properties.has_changes() {
+                        self.definition(&format!("routine {}.{}", schema_name, routine_signature), definition);
+                    }
+
+                    let routine_identity = routine_identity(schema_name, routine_signature);
+                    self.add_privilege_statements(PrivilegeTarget::Routine { routine_identity: &routine_identity }, privileges);
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn add_table_statements(&mut self, schema_name: &str, report: &Report<TableComparison>) {
+        for table in &report.entries {
+            if let TableAdded { definition, .. } = table {
+                self.statement(definition.clone());
+            }
+        }
+
+        for table in &report.entries {
+            if let TableMaintained { table_name, columns, constraints, indices, partitions, policies, privileges, properties, triggers } = table {
+                if !table.has_changes() {
+                    continue
+                }
+
+                let name = qualified_name(schema_name, table_name);
+
+                if changed_properties(properties).any(|(p, _, _)| PARTITION_ATTACHMENT_PROPERTIES.contains(&p)) {
+                    let left_parent = left_property_value(properties, "partition_parent_name")
+                        .map(|p| qualified_name(left_property_value(properties, "partition_parent_schema").unwrap_or(schema_name), p));
+                    let right_parent = property_value(properties, "partition_parent_name")
+                        .map(|p| qualified_name(property_value(properties, "partition_parent_schema").unwrap_or(schema_name), p));
+
+                    self.add_partition_attachment_statements(&name, left_parent, right_parent, property_value(properties, "partition_bound"));
+                }
+
+                for (property_name, _, right_value) in changed_properties(properties) {
+                    match (property_name, right_value) {
+                        _ if PARTITION_ATTACHMENT_PROPERTIES.contains(&property_name) => (),
+                        ("parents", _) => (),
+                        ("row_security", Some("YES")) => self.statement(format!("ALTER TABLE {} ENABLE ROW LEVEL SECURITY", name)),
+                        ("row_security", _) => self.statement(format!("ALTER TABLE {} DISABLE ROW LEVEL SECURITY", name)),
+                        ("force_row_security", Some("YES")) => self.statement(format!("ALTER TABLE {} FORCE ROW LEVEL SECURITY", name)),
+                        ("force_row_security", _) => self.statement(format!("ALTER TABLE {} NO FORCE ROW LEVEL SECURITY", name)),
+                        _ => self.unsupported_property(&format!("table {}", name), properties, property_name),
+                    }
+                }
+
+                // view columns follow the view definition
+                if property_value(properties, "table_type") == Some("VIEW") && !changed_properties(properties).any(|(p, _, _)| p == "table_type") {
+                    self.add_privilege_statements(PrivilegeTarget::Table { table_name: &name }, privileges);
+                    continue
+                }
+
+                self.add_table_policy_statements(&name, policies, true);
+                self.add_table_trigger_statements(&name, triggers, true);
+                self.add_table_constraint_statements(&name, constraints, true);
+                self.add_table_index_statements(schema_name, constraints, indices, true);
+                self.add_table_partition_statements(schema_name, partitions, true);
+                // inherited columns can only be dropped once detached, and parents only added once the columns exist
+                self.add_table_inheritance_statements(&name, properties, true);
+                self.add_table_column_statements(&name, columns);
+                self.add_table_inheritance_statements(&name, properties, false);
+                self.add_table_constraint_statements(&name, constraints, false);
+                self.add_table_index_statements(schema_name, constraints, indices, false);
+                self.add_table_partition_statements(schema_name, partitions, false);
+                self.add_table_trigger_statements(&name, triggers, false);
+                self.add_table_policy_statements(&name, policies, false);
+                self.add_privilege_statements(PrivilegeTarget::Table { table_name: &name }, privileges);
+            }
+        }
+
+        for table in &report.entries {
+            if let TableRemoved { table_name, .. } = table {
+                self.statement(format!("DROP TABLE {}", qualified_name(schema_name, table_name)));
+            }
+        }
+    }
+
+    fn add_table_inheritance_statements(&mut self, table_name: &str, properties: &Report<PropertyComparison>, removals: bool) {
+        if let Some((_, left_value, right_value)) = changed_properties(properties).find(|(p, _, _)| *p == "parents") {
+            let left_parents = inherited_tables(left_value);
+            let right_parents = inherited_tables(right_value);
+
+            if removals {
+                for parent in left_parents.iter().filter(|p| !right_parents.contains(p)) {
+                    self.statement(format!("ALTER TABLE {} NO INHERIT {}", table_name, parent));
+                }
+            } else {
+                for parent in right_parents.iter().filter(|p| !left_parents.contains(p)) {
+                    self.statement(format!("ALTER TABLE {} INHERIT {}", table_name, parent));
+                }
+            }
+        }
+    }
+
+    fn add_table_partition_statements(&mut self, schema_name: &str, report: &Report<PartitionComparison>, removals: bool) {
+        if removals {
+            // partitions are listed parents first, so sub-partitions are dropped before their parent
+            for partition in report.entries.iter().rev() {
+                if let PartitionRemoved { partition_name } = partition {
+                    self.statement(format!("DROP TABLE {}", qualified_name(schema_name, partition_name)));
+                }
+            }
+
+            return
+        }
+
+        for partition in &report.entries {
+            match partition {
+                PartitionAdded { definition, .. } => self.statement(definition.clone()),
+                PartitionMaintained { partition_name, properties } => {
+                    let name = qualified_name(schema_name, partition_name);
+
+                    if changed_properties(properties).any(|(p, _, _)| PARTITION_ATTACHMENT_PROPERTIES.contains(&p)) {
+                        let left_parent = left_property_value(properties, "partition_parent_name").map(|p| qualified_name(schema_name, p));
+                        let right_parent = property_value(properties, "partition_parent_name").map(|p| qualified_name(schema_name, p));
+
+                        self.add_partition_attachment_statements(&name, left_parent, right_parent, property_value(properties, "partition_bound"));
+                    }
+
+                    for (property_name, _, _) in changed_properties(properties) {
+                        if !PARTITION_ATTACHMENT_PROPERTIES.contains(&property_name) {
+                            self.unsupported_property(&format!("partition {}", name), properties, property_name);
+                        }
+                    }
+                },
+                PartitionRemoved { .. } => (),
+            }
+        }
+    }
+
+    fn add_partition_attachment_statements(&mut self, partition_name: &str, left_parent: Option<String>, right_parent: Option<String>, partition_bound: Option<&str>) {
+        if let Some(left_parent) = left_parent {
+            self.statement(format!("ALTER TABLE {} DETACH PARTITION {}", left_parent, partition_name));
+        }
+
+        if let Some(right_parent) = right_parent {
+            self.statement(format!("ALTER TABLE {} ATTACH PARTITION {} {}", right_parent, partition_name, partition_bound.unwrap_or("DEFAULT")));
+        }
+    }
+
+    fn add_table_column_statements(&mut self, table_name: &str, report: &Report<ColumnComparison>) {
+        for column in &report.entries {
+            if let ColumnRemoved { column_name } = column {
+                self.statement(format!("ALTER TABLE {} DROP COLUMN {}", table_name, quote_identifier(column_name)));
+            }
+        }
+
+        for column in &report.entries {
+            if let ColumnAdded { definition, .. } = column {
+                self.statement(format!("ALTER TABLE {} ADD COLUMN {}", table_name, definition));
+            }
+        }
+
+        for column in &report.entries {
+            if let ColumnMaintained { column_name, properties, privileges } = column {
+                let column = quote_identifier(column_name);
+                let type_changed = changed_properties(properties).any(|(p, _, _)| p == "column_type");
+
+                for (property_name, _, right_value) in changed_properties(properties) {
+                    match (property_name, right_value) {
+                        ("column_type", Some(column_type)) => self.statement(format!("ALTER TABLE {} ALTER COLUMN {} TYPE {} USING {}::{}", table_name, column, column_type, column, column_type)),
+                        ("is_nullable", Some("NO")) => self.statement(format!("ALTER TABLE {} ALTER COLUMN {} SET NOT NULL", table_name, column)),
+                        ("is_nullable", _) => self.statement(format!("ALTER TABLE {} ALTER COLUMN {} DROP NOT NULL", table_name, column)),
+                        ("column_default", None) => self.statement(format!("ALTER TABLE {} ALTER COLUMN {} DROP DEFAULT", table_name, column)),
+                        ("column_default", Some(default)) => self.statement(format!("ALTER TABLE {} ALTER COLUMN {} SET DEFAULT {}", table_name, column, default)),
+                        _ if type_changed && COLUMN_TYPE_PROPERTIES.contains(&property_name) => (),
+                        // follow the parents of the table
+                        ("is_local" | "inheritance_count", _) => (),
+                        _ => self.unsupported_property(&format!("column {}.{}", table_name, column), properties, property_name),
+                    }
+                }
+
+                self.add_privilege_statements(PrivilegeTarget::Column { table_name, column_name }, privileges);
+            }
+        }
+    }
+
+    fn add_table_constraint_statements(&mut self, table_name: &str, report: &Report<TableConstraintComparison>, removals: bool) {
+        for constraint in &report.entries {
+            match constraint {
+                ConstraintRemoved { constraint_name } if removals => {
+                    self.statement(format!("ALTER TABLE {} DROP CONSTRAINT {}", table_name, quote_identifier(constraint_name)));
+                },
+                ConstraintAdded { constraint_name, definition } if !removals => {
+                    self.add_constraint(table_name, constraint_name, definition.as_deref());
+                },
+                ConstraintMaintained { constraint_name, properties } if constraint.has_changes() => {
+                    if removals {
+                        self.statement(format!("ALTER TABLE {} DROP CONSTRAINT {}", table_name, quote_identifier(constraint_name)));
+                    } else {
+                        self.add_constraint(table_name, constraint_name, property_value(properties, "definition"));
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn add_constraint(&mut self, table_name: &str, constraint_name: &str, definition: Option<&str>) {
+        match definition {
+            Some(definition) => self.statement(format!("ALTER TABLE {} ADD CONSTRAINT {} {}", table_name, quote_identifier(constraint_name), definition)),
+            None => self.comment(format!("constraint '{}' on table {} has no definition and must be created manually", constraint_name, table_name)),
+        }
+    }
+
+    fn add_table_index_statements(&mut self, schema_name: &str, constraints: &Report<TableConstraintComparison>, report: &Report<IndexComparison>, removals: bool) {
+        for index in &report.entries {
+            let index_name = match index {
+                IndexAdded { index_name, .. } | IndexRemoved { index_name } | IndexMaintained { index_name, .. } => index_name,
+            };
+
+            // indices backing constraints follow their constraint
+            if constraints.entries.iter().any(|c| constraint_name(c) == index_name) {
+                continue
+            }
+
+            match index {
+                IndexRemoved { index_name } if removals => {
+                    self.statement(format!("DROP INDEX {}", qualified_name(schema_name, index_name)));
+                },
+                IndexAdded { definition, .. } if !removals => self.statement(definition.clone()),
+                IndexMaintained { index_name, properties } if index.has_changes() => {
+                    if removals {
+                        self.statement(format!("DROP INDEX {}", qualified_name(schema_name, index_name)));
+                    } else if let Some(definition) = property_value(properties, "definition") {
+                        self.statement(String::from(definition));
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn add_table_trigger_statements(&mut self, table_name: &str, report: &Report<TableTriggerComparison>, removals: bool) {
+        let mut trigger_names: Vec<&str> = Vec::new();
+
+        for trigger in &report.entries {
+            let trigger_name = match trigger {
+                TriggerAdded { trigger_name, .. } | TriggerRemoved { trigger_name, .. } | TriggerMaintained { trigger_name, .. } => trigger_name,
+            };
+
+            if !trigger_names.contains(&trigger_name.as_str()) {
+                trigger_names.push(trigger_name);
+            }
+        }
+
+        // information_schema reports a trigger once per event, so changes are applied per trigger name
+        for trigger_name in trigger_names {
+            let events: Vec<&TableTriggerComparison> = report.entries.iter().filter(|t| trigger_name_of(t) == trigger_name).collect();
+
+            if !events.iter().any(|t| t.has_changes()) {
+                continue
+            }
+
+            let exists_on_left = events.iter().any(|t| matches!(t, TriggerRemoved { .. } | TriggerMaintained { .. }));
+            let right_definition = events.iter().find_map(|t| match t {
+                TriggerAdded { definition, .. } | TriggerMaintained { definition, .. } => Some(definition),
+                TriggerRemoved { .. } => None,
+            });
+
+            if removals {
+                if exists_on_left {
+                    self.statement(format!("DROP TRIGGER {} ON {}", quote_identifier(trigger_name), table_name));
+                }
+            } else if let Some(definition) = right_definition {
+                self.definition(&format!("trigger '{}' on table {}", trigger_name, table_name), definition);
+            }
+        }
+    }
+
+    fn add_table_policy_statements(&mut self, table_name: &str, report: &Report<PolicyComparison>, removals: bool) {
+        for policy in &report.entries {
+            match policy {
+                PolicyRemoved { policy_name } if removals => {
+                    self.statement(format!("DROP POLICY {} ON {}", quote_identifier(policy_name), table_name));
+                },
+                PolicyAdded { definition, .. } if !removals => self.statement(definition.clone()),
+                PolicyMaintained { policy_name, definition, .. } if policy.has_changes() => {
+                    if removals {
+                        self.statement(format!("DROP POLICY {} ON {}", quote_identifier(policy_name), table_name));
+                    } else {
+                        self.statement(definition.clone());
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn add_view_statements(&mut self, schema_name: &str, report: &Report<ViewComparison>, removals: bool) {
+        for view in &report.entries {
+            match view {
+                ViewRemoved { view_name } if removals => {
+                    self.statement(format!("DROP VIEW {}", qualified_name(schema_name, view_name)));
+                },
+                ViewAdded { definition, .. } if !removals => self.statement(definition.clone()),
+                ViewMaintained { view_name, properties } if !removals => {
+                    let name = qualified_name(schema_name, view_name);
+                    let definition_changed = changed_properties(properties).any(|(p, _, _)| p == "view_definition");
+
+                    for (property_name, _, right_value) in changed_properties(properties) {
+                        match (property_name, right_value) {
+                            ("view_definition", Some(definition)) => self.statement(format!("CREATE OR REPLACE VIEW {} AS{}", name, definition.trim_end().trim_end_matches(';'))),
+                            _ if definition_changed && VIEW_DERIVED_PROPERTIES.contains(&property_name) => (),
+                            _ => self.unsupported_property(&format!("view {}", name), properties, property_name),
+                        }
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn add_materialized_view_statements(&mut self, schema_name: &str, report: &Report<MaterializedViewComparison>, removals: bool) {
+        for view in &report.entries {
+            match view {
+                MaterializedViewRemoved { view_name } if removals => {
+                    self.statement(format!("DROP MATERIALIZED VIEW {}", qualified_name(schema_name, view_name)));
+                },
+                MaterializedViewAdded { definition, .. } if !removals => self.statement(definition.clone()),
+                MaterializedViewMaintained { view_name, definition, indices, privileges, properties } => {
+                    let name = qualified_name(schema_name, view_name);
+
+                    // a materialized view cannot be replaced, so a changed query means recreating it with its indices and privileges
+                    if changed_properties(properties).any(|(p, _, _)| p == "view_definition") {
+                        if removals {
+                            self.statement(format!("DROP MATERIALIZED VIEW {}", name));
+                        } else {
+                            self.statement(definition.clone());
+
+                            // the owner's own privileges come with the view
+                            for privilege in &privileges.entries {
+                                if let PrivilegeAdded { privilege_name, grantor, grantee } | PrivilegeMaintained { privilege_name, grantor, grantee } = privilege {
+                                    if grantor == grantee {
+                                        continue
+                                    }
+
+                                    self.statement(format!("GRANT {} TO {}", privilege_clause(&PrivilegeTarget::Table { table_name: &name }, privilege_name), grantee_name(grantee)));
+                                }
+                            }
+                        }
+
+                        continue
+                    }
+
+                    if removals {
+                        self.add_table_index_statements(schema_name, &Report { entries: vec![] }, indices, true);
+                        continue
+                    }
+
+                    for (property_name, _, right_value) in changed_properties(properties) {
+                        match (property_name, right_value) {
+                            ("view_owner", Some(owner)) => self.statement(format!("ALTER MATERIALIZED VIEW {} OWNER TO {}", name, quote_identifier(owner))),
+                            ("table_space", None) => self.statement(format!("ALTER MATERIALIZED VIEW {} SET TABLESPACE pg_default", name)),
+                            ("table_space", Some(table_space)) => self.statement(format!("ALTER MATERIALIZED VIEW {} SET TABLESPACE {}", name, quote_identifier(table_space))),
+                            ("is_populated", Some("YES")) => self.statement(format!("REFRESH MATERIALIZED VIEW {}", name)),
+                            ("is_populated", _) => self.statement(format!("REFRESH MATERIALIZED VIEW {} WITH NO DATA", name)),
+                            _ => self.unsupported_property(&format!("materialized view {}", name), properties, property_name),
+                        }
+                    }
+
+                    self.add_table_index_statements(schema_name, &Report { entries: vec![] }, indices, false);
+                    self.add_privilege_statements(PrivilegeTarget::Table { table_name: &name }, privileges);
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn add_privilege_statements(&mut self, target: PrivilegeTarget, report: &Report<PrivilegeComparison>) {
+        for privilege in &report.entries {
+            match privilege {
+                PrivilegeAdded { privilege_name, grantee, .. } => {
+                    self.statement(format!("GRANT {} TO {}", privilege_clause(&target, privilege_name), grantee_name(grantee)));
+                },
+                PrivilegeRemoved { privilege_name, grantee, .. } => {
+                    self.statement(format!("REVOKE {} FROM {}", privilege_clause(&target, privilege_name), grantee_name(grantee)));
+                },
+                PrivilegeMaintained { .. } => (),
+            }
+        }
+    }
+
+    fn definition(&mut self, object: &str, definition: &Option<String>) {
+        match definition {
+            Some(definition) => self.statement(String::from(definition.trim_end())),
+            None => self.comment(format!("{} has no definition and must be created manually", object)),
+        }
+    }
+
+    fn unsupported_property(&mut self, object: &str, properties: &Report<PropertyComparison>, property_name: &str) {
+        if let Some((_, left_value, right_value)) = changed_properties(properties).find(|(p, _, _)| *p == property_name) {
+            self.comment(format!("{}: property '{}' changed from '{}' to '{}' and must be migrated manually", object, property_name, left_value.unwrap_or(NONE), right_value.unwrap_or(NONE)));
+        }
+    }
+
+    fn statement(&mut self, statement: String) {
+        self.statements.push(format!("{};", statement));
+    }
+
+    fn comment(&mut self, comment: String) {
+        self.statements.push(format!("-- {}", comment));
+    }
+}
+
+impl Display for Migration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.statements.is_empty() {
+            // as pg_dump does, routine bodies may reference objects created later in the script
+            writeln!(f, "SET check_function_bodies = false;")?;
+        }
+
+        for statement in &self.statements {
+            writeln!(f, "{}", statement)?;
+        }
+
+        Ok(())
+    }
+}
+
+// how a comment shows a property without a value
+const NONE: &str = "<none>";
+
+fn changed_properties(properties: &Report<PropertyComparison>) -> impl Iterator<Item = (&str, Option<&str>, Option<&str>)> {
+    properties.entries.iter().filter_map(|p| match p {
+        PropertyChanged { property_name, left_value, right_value, .. } => Some((property_name.as_str(), left_value.as_deref(), right_value.as_deref())),
+        PropertyUnchanged { .. } => None,
+    })
+}
+
+fn property_value<'a>(properties: &'a Report<PropertyComparison>, property_name: &str) -> Option<&'a str> {
+    properties.entries.iter()
+        .find_map(|p| match p {
+            PropertyChanged { property_name: name, right_value: value, .. } |
+            PropertyUnchanged { property_name: name, value } if name == property_name => value.as_deref(),
+            _ => None,
+        })
+}
+
+fn inherited_tables(parents: Option<&str>) -> Vec<&str> {
+    parents.map_or(Vec::new(), |parents| parents.split(", ").collect())
+}
+
+fn left_property_value<'a>(properties: &'a Report<PropertyComparison>, property_name: &str) -> Option<&'a str> {
+    properties.entries.iter()
+        .find_map(|p| match p {
+            PropertyChanged { property_name: name, left_value: value, .. } |
+            PropertyUnchanged { property_name: name, value } if name == property_name => value.as_deref(),
+            _ => None,
+        })
+}
+
+fn constraint_name(constraint: &TableConstraintComparison) -> &str {
+    match constraint {
+        ConstraintAdded { constraint_name, .. } | ConstraintRemoved { constraint_name } | ConstraintMaintained { constraint_name, .. } => constraint_name,
+    }
+}
+
+fn trigger_name_of(trigger: &TableTriggerComparison) -> &str {
+    match trigger {
+        TriggerAdded { trigger_name, .. } | TriggerRemoved { trigger_name, .. } | TriggerMaintained { trigger_name, .. } => trigger_name,
+    }
+}
+
+fn privilege_clause(target: &PrivilegeTarget, privilege_name: &str) -> String {
+    match target {
+        PrivilegeTarget::Table { table_name } => format!("{} ON TABLE {}", privilege_name, table_name),
+        PrivilegeTarget::Column { table_name, column_name } => format!("{} ({}) ON TABLE {}", privilege_name, quote_identifier(column_name), table_name),
+        PrivilegeTarget::Routine { routine_identity } => format!("{} ON ROUTINE {}", privilege_name, routine_identity),
+        PrivilegeTarget::Type { type_name } => format!("{} ON TYPE {}", privilege_name, type_name),
+    }
+}
+
+fn type_keyword(type_kind: &str) -> &'static str {
+    if type_kind == "DOMAIN" {
+        "DOMAIN"
+    } else {
+        "TYPE"
+    }
+}
+
+/// Splits an enum label list, e.g. `'a', 'it''s'`, into its quoted labels.
+fn enum_labels(labels: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut start = None;
+    let mut chars = labels.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        match (c, start) {
+            ('\'', None) => start = Some(index),
+            ('\'', Some(_)) if chars.peek().map(|(_, c)| *c) == Some('\'') => _ = chars.next(),
+            ('\'', Some(label_start)) => {
+                result.push(&labels[label_start..=index]);
+                start = None;
+            },
+            _ => (),
+        }
+    }
+
+    result
+}
+
+/// The `ADD VALUE` clauses for labels added to an enum, which is the only label change `ALTER TYPE` can make
+/// in place. Returns `None` if labels were removed or reordered.
+fn added_enum_labels(left: &str, right: &str) -> Option<Vec<String>> {
+    let left_labels = enum_labels(left);
+    let right_labels = enum_labels(right);
+
+    let kept_labels: Vec<&str> = right_labels.iter().filter(|l| left_labels.contains(l)).cloned().collect();
+    if kept_labels != left_labels {
+        return None
+    }
+
+    let first_kept = right_labels.iter().position(|l| left_labels.contains(l));
+    let last_kept = right_labels.iter().rposition(|l| left_labels.contains(l));
+
+    // labels are added in order, so each one after the first can follow the label before it
+    let clauses = right_labels.iter().enumerate()
+        .filter(|(_, label)| !left_labels.contains(label))
+        .map(|(index, label)| match (first_kept, last_kept) {
+            (Some(first_kept), _) if index == 0 => format!("{} BEFORE {}", label, right_labels[first_kept]),
+            (_, Some(last_kept)) if index < last_kept => format!("{} AFTER {}", label, right_labels[index - 1]),
+            _ => String::from(*label),
+        })
+        .collect();
+
+    Some(clauses)
+}
+
+fn grantee_name(grantee: &str) -> String {
+    if grantee == "PUBLIC" {
+        String::from(grantee)
+    } else {
+        quote_identifier(grantee)
+    }
+}
+
+/// Converts a report routine signature, e.g. `name(a IN db.pg_catalog.int4, b OUT db.pg_catalog.text)`,
+/// into the identity form accepted by `DROP ROUTINE`, e.g. `schema.name(pg_catalog.int4)`.
+fn routine_identity(schema_name: &str, routine_signature: &str) -> String {
+    let (routine_name, parameters) = routine_signature.split_once('(').unwrap_or((routine_signature, ")"));
+    let parameters = parameters.strip_suffix(')').unwrap_or(parameters);
+
+    let argument_types: Vec<String> = parameters.split(", ")
+        .filter(|p| !p.is_empty())
+        .filter_map(|p| {
+            let mut parts = p.rsplitn(3, ' ');
+            let type_name = parts.next()?;
+            let mode = parts.next()?;
+
+            if mode == "OUT" {
+                return None
+            }
+
+            let (_, type_name) = type_name.split_once('.')?;
+            let (type_schema, type_name) = type_name.split_once('.')?;
+
+            Some(qualified_name(type_schema, type_name))
+        })
+        .collect();
+
+    format!("{}({})", qualified_name(schema_name, routine_name), argument_types.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_routine_identity() {
+        let signature = "routine_changed(a IN postgres.pg_catalog.int4, b IN postgres.pg_catalog.text, c OUT postgres.pg_catalog.int4)";
+
+        assert_eq!("test.routine_changed(pg_catalog.int4, pg_catalog.text)", routine_identity("test", signature));
+        assert_eq!("test.\"Trigger\"()", routine_identity("test", "Trigger()"));
+    }
+
+    #[test]
+    fn test_added_enum_labels() {
+        assert_eq!(Some(vec![String::from("'c'"), String::from("'it''s, ''d'''")]), added_enum_labels("'a', 'b'", "'a', 'b', 'c', 'it''s, ''d'''"));
+        assert_eq!(Some(vec![String::from("'x' BEFORE 'a'"), String::from("'y' AFTER 'a'")]), added_enum_labels("'a', 'b'", "'x', 'a', 'y', 'b'"));
+        assert_eq!(Some(vec![String::from("'a' BEFORE 'c'"), String::from("'b' AFTER 'a'")]), added_enum_labels("'c'", "'a', 'b', 'c'"));
+        assert_eq!(Some(vec![String::from("'a'")]), added_enum_labels("", "'a'"));
+        assert_eq!(None, added_enum_labels("'a', 'b'", "'b', 'a', 'c'"));
+        assert_eq!(None, added_enum_labels("'a', 'b'", "'a'"));
+    }
+}
@@ -1,5 +1,11 @@
+use std::collections::HashMap;
 use std::iter::Peekable;
 use std::str::Chars;
+use similar::TextDiff;
+
+mod sql;
+
+pub use sql::normalize_sql;
 
 pub trait EqualIgnoreWhitespace {
     fn eq_ignore_whitespace(&self, other: &str) -> bool;
@@ -7,8 +13,8 @@ pub trait EqualIgnoreWhitespace {
 
 impl EqualIgnoreWhitespace for &str {
     fn eq_ignore_whitespace(&self, other: &str) -> bool {
-        let mut self_iter = self.chars().into_iter().peekable();
-        let mut other_iter = other.chars().into_iter().peekable();
+        let mut self_iter = self.chars().peekable();
+        let mut other_iter = other.chars().peekable();
 
         let mut self_char: Option<char> = None;
         let mut other_char: Option<char> = None;
@@ -19,7 +25,7 @@ impl EqualIgnoreWhitespace for &str {
             }
         }
 
-        let same = loop {
+        loop {
             if self_char.map(|c| c.is_whitespace()).unwrap_or(true) {
                 skip_whitespace(&mut self_iter);
             }
@@ -44,10 +50,134 @@ impl EqualIgnoreWhitespace for &str {
             if self_char != other_char {
                 break false;
             }
-        };
+        }
+    }
+}
+
+const DIFF_CONTEXT_LINES: usize = 3;
+
+const RESERVED_WORDS: &[&str] = &[
+    "all", "analyse", "analyze", "and", "any", "array", "as", "asc", "asymmetric", "both", "case", "cast",
+    "check", "collate", "column", "constraint", "create", "current_catalog", "current_date", "current_role",
+    "current_time", "current_timestamp", "current_user", "default", "deferrable", "desc", "distinct", "do",
+    "else", "end", "except", "false", "fetch", "for", "foreign", "from", "grant", "group", "having", "in",
+    "initially", "intersect", "into", "lateral", "leading", "limit", "localtime", "localtimestamp", "not",
+    "null", "offset", "on", "only", "or", "order", "placing", "primary", "references", "returning", "select",
+    "session_user", "some", "symmetric", "table", "then", "to", "trailing", "true", "union", "unique", "user",
+    "using", "variadic", "when", "where", "window", "with",
+];
+
+pub fn quote_identifier(identifier: &str) -> String {
+    let is_plain = identifier.chars().next().is_some_and(|c| c.is_ascii_lowercase() || c == '_') &&
+        identifier.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '$') &&
+        !RESERVED_WORDS.contains(&identifier);
+
+    if is_plain {
+        String::from(identifier)
+    } else {
+        format!("\"{}\"", identifier.replace('"', "\"\""))
+    }
+}
+
+pub fn qualified_name(schema_name: &str, object_name: &str) -> String {
+    format!("{}.{}", quote_identifier(schema_name), quote_identifier(object_name))
+}
+
+pub fn unified_diff(left: &str, right: &str) -> String {
+    TextDiff::from_lines(left, right)
+        .unified_diff()
+        .context_radius(DIFF_CONTEXT_LINES)
+        .missing_newline_hint(false)
+        .header("left", "right")
+        .to_string()
+}
+
+pub fn quote_literal(literal: &str) -> String {
+    format!("'{}'", literal.replace('\'', "''"))
+}
+
+/// Replaces names qualified by any of the schemas in `renames` with names qualified by the schema it maps to.
+pub fn replace_schema_qualifiers(text: &str, renames: &HashMap<String, String>) -> String {
+    let qualifiers: Vec<(String, String)> = renames.iter()
+        .map(|(from, to)| (format!("{}.", quote_identifier(from)), format!("{}.", quote_identifier(to))))
+        .collect();
+
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    let mut previous: Option<char> = None;
+
+    while let Some(c) = rest.chars().next() {
+        let at_boundary = previous.is_none_or(|p| !(p.is_alphanumeric() || p == '_' || p == '$' || p == '"'));
+        let qualifier = qualifiers.iter().find(|(from, _)| at_boundary && rest.starts_with(from.as_str()));
+
+        match qualifier {
+            Some((from, to)) => {
+                result.push_str(to);
+                rest = &rest[from.len()..];
+                previous = Some('.');
+            },
+            None => {
+                result.push(c);
+                rest = &rest[c.len_utf8()..];
+                previous = Some(c);
+            },
+        }
+    }
+
+    result
+}
+
+/// Replaces the schemas in `renames` with the schemas they map to within the `search_path` setting of a routine
+/// configuration, such as `search_path=app_v2, public; work_mem=64MB`.
+pub fn replace_search_path_schemas(configuration: &str, renames: &HashMap<String, String>) -> String {
+    configuration.split("; ")
+        .map(|setting| match setting.strip_prefix("search_path=") {
+            Some(search_path) => {
+                let schemas: Vec<String> = search_path.split(", ")
+                    .map(|schema| match renames.iter().find(|(from, _)| quote_identifier(from) == schema) {
+                        Some((_, to)) => quote_identifier(to),
+                        None => String::from(schema),
+                    })
+                    .collect();
+
+                format!("search_path={}", schemas.join(", "))
+            },
+            None => String::from(setting),
+        })
+        .collect::<Vec<String>>()
+        .join("; ")
+}
+
+/// Matches `text` against a pattern in which `*` stands for any run of characters and `?` for any one character.
+pub fn matches_glob(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
 
-        same
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            },
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            },
+            _ => match backtrack {
+                Some((star, star_text)) => {
+                    backtrack = Some((star, star_text + 1));
+                    p = star + 1;
+                    t = star_text + 1;
+                },
+                None => return false,
+            },
+        }
     }
+
+    pattern[p..].iter().all(|&c| c == '*')
 }
 
 #[cfg(test)]
@@ -55,6 +185,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_eq_ignore_whitespace() {
         let left = "this is a test";
         let right = "  this\tis \t a      test  \t";
@@ -63,4 +194,59 @@ mod tests {
 
         assert_eq!(true, actual);
     }
+
+    #[test]
+    fn test_quote_identifier() {
+        assert_eq!("audit_log", quote_identifier("audit_log"));
+        assert_eq!("\"AuditLog\"", quote_identifier("AuditLog"));
+        assert_eq!("\"audit log\"", quote_identifier("audit log"));
+        assert_eq!("\"order\"", quote_identifier("order"));
+        assert_eq!("\"say \"\"hi\"\"\"", quote_identifier("say \"hi\""));
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let left = "SELECT\n    a,\n    b\nFROM t\n";
+        let right = "SELECT\n    a,\n    c\nFROM t\n";
+
+        let expected = "--- left\n+++ right\n@@ -1,4 +1,4 @@\n SELECT\n     a,\n-    b\n+    c\n FROM t\n";
+
+        assert_eq!(expected, unified_diff(left, right));
+    }
+
+    #[test]
+    fn test_matches_glob() {
+        assert!(matches_glob("audit_*", "audit_log"));
+        assert!(matches_glob("*_tmp", "orders_tmp"));
+        assert!(matches_glob("pgp_*_decrypt", "pgp_sym_decrypt"));
+        assert!(matches_glob("log?", "log1"));
+        assert!(matches_glob("*", ""));
+        assert!(!matches_glob("audit_*", "audits"));
+        assert!(!matches_glob("log?", "log"));
+        assert!(!matches_glob("*_tmp", "tmp_orders"));
+    }
+
+    #[test]
+    fn test_replace_schema_qualifiers() {
+        let renames = HashMap::from([(String::from("app_v2"), String::from("app_v1")), (String::from("Tenant"), String::from("tenant_a"))]);
+
+        assert_eq!("SELECT a.id FROM app_v1.account a", replace_schema_qualifiers("SELECT a.id FROM app_v2.account a", &renames));
+        assert_eq!("nextval('tenant_a.seq'::regclass)", replace_schema_qualifiers("nextval('\"Tenant\".seq'::regclass)", &renames));
+        assert_eq!("my_app_v2.account", replace_schema_qualifiers("my_app_v2.account", &renames));
+    }
+
+    #[test]
+    fn test_replace_search_path_schemas() {
+        let renames = HashMap::from([(String::from("app_v2"), String::from("app_v1")), (String::from("Tenant"), String::from("tenant_a"))]);
+
+        assert_eq!("search_path=app_v1, public; work_mem=64MB", replace_search_path_schemas("search_path=app_v2, public; work_mem=64MB", &renames));
+        assert_eq!("search_path=\"$user\", tenant_a", replace_search_path_schemas("search_path=\"$user\", \"Tenant\"", &renames));
+        assert_eq!("search_path=my_app_v2", replace_search_path_schemas("search_path=my_app_v2", &renames));
+    }
+
+    #[test]
+    fn test_quote_literal() {
+        assert_eq!("'1.4'", quote_literal("1.4"));
+        assert_eq!("'it''s'", quote_literal("it's"));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,217 @@
+use sqlformat::{FormatOptions, QueryParams};
+
+const OPERATOR_CHARS: &str = "+-*/<>=~!@#%^&|`?:";
+
+// keywords and punctuation a parenthesised expression can stand between without the parentheses changing its meaning
+const EXPRESSION_BOUNDARIES: &[&str] = &[
+    "(", ")", ",", ";", "and", "as", "by", "else", "end", "except", "from", "having", "intersect", "join", "limit",
+    "offset", "on", "or", "returning", "select", "then", "union", "when", "where",
+];
+
+/// Reformats SQL so that definitions differing only in layout, keyword and identifier case, comments or redundant
+/// parentheses compare equal.
+pub fn normalize_sql(sql: &str) -> String {
+    sqlformat::format(&normalized_tokens(sql).join(" "), &QueryParams::None, FormatOptions { uppercase: true, ..FormatOptions::default() })
+}
+
+fn normalized_tokens(sql: &str) -> Vec<String> {
+    remove_redundant_parentheses(tokenize(sql))
+}
+
+fn tokenize(sql: &str) -> Vec<String> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let start = index;
+        let c = chars[index];
+        let next = chars.get(index + 1).copied();
+
+        if c.is_whitespace() {
+            index += 1;
+        } else if c == '-' && next == Some('-') {
+            while index < chars.len() && chars[index] != '\n' {
+                index += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            index = skip_block_comment(&chars, index);
+        } else if c == '\'' || c == '"' {
+            index = skip_quoted(&chars, index, c);
+            tokens.push(chars[start..index].iter().collect());
+        } else if let Some(tag_end) = dollar_quote_tag_end(&chars, index) {
+            let tag: String = chars[start..tag_end].iter().collect();
+            let body: String = chars[tag_end..].iter().collect();
+
+            match body.find(&tag) {
+                Some(body_end) => {
+                    tokens.push(format!("$$ {} $$", normalized_tokens(&body[..body_end]).join(" ")));
+                    index = tag_end + body[..body_end].chars().count() + tag.chars().count();
+                },
+                None => {
+                    tokens.push(body);
+                    index = chars.len();
+                },
+            }
+        } else if c.is_alphabetic() || c == '_' || c == '$' {
+            index += 1;
+            while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_' || chars[index] == '$') {
+                index += 1;
+            }
+            tokens.push(chars[start..index].iter().collect::<String>().to_lowercase());
+        } else if c.is_ascii_digit() {
+            while index < chars.len() && (chars[index].is_ascii_alphanumeric() || chars[index] == '.') {
+                index += 1;
+            }
+            tokens.push(chars[start..index].iter().collect());
+        } else if OPERATOR_CHARS.contains(c) {
+            index += 1;
+            while index < chars.len() && OPERATOR_CHARS.contains(chars[index]) && !starts_comment(&chars, index) {
+                index += 1;
+            }
+            tokens.push(chars[start..index].iter().collect());
+        } else {
+            index += 1;
+            tokens.push(c.to_string());
+        }
+    }
+
+    tokens
+}
+
+fn starts_comment(chars: &[char], index: usize) -> bool {
+    matches!((chars[index], chars.get(index + 1)), ('-', Some('-')) | ('/', Some('*')))
+}
+
+fn skip_block_comment(chars: &[char], start: usize) -> usize {
+    let mut depth = 0;
+    let mut index = start;
+
+    while index < chars.len() {
+        match (chars[index], chars.get(index + 1)) {
+            ('/', Some('*')) => {
+                depth += 1;
+                index += 2;
+            },
+            ('*', Some('/')) => {
+                depth -= 1;
+                index += 2;
+
+                if depth == 0 {
+                    break;
+                }
+            },
+            _ => index += 1,
+        }
+    }
+
+    index
+}
+
+fn skip_quoted(chars: &[char], start: usize, quote: char) -> usize {
+    let mut index = start + 1;
+
+    while index < chars.len() {
+        if chars[index] == quote {
+            if chars.get(index + 1) == Some(&quote) {
+                index += 2;
+                continue;
+            }
+
+            return index + 1;
+        }
+
+        index += 1;
+    }
+
+    index
+}
+
+// the index just past the opening tag of a dollar-quoted string starting at `start`, if there is one
+fn dollar_quote_tag_end(chars: &[char], start: usize) -> Option<usize> {
+    if chars[start] != '$' || chars.get(start + 1).is_some_and(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut index = start + 1;
+    while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') {
+        index += 1;
+    }
+
+    (chars.get(index) == Some(&'$')).then_some(index + 1)
+}
+
+fn remove_redundant_parentheses(mut tokens: Vec<String>) -> Vec<String> {
+    while let Some((open, close)) = find_redundant_parentheses(&tokens) {
+        tokens.remove(close);
+        tokens.remove(open);
+    }
+
+    tokens
+}
+
+fn find_redundant_parentheses(tokens: &[String]) -> Option<(usize, usize)> {
+    let mut opens = Vec::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        match token.as_str() {
+            "(" => opens.push(index),
+            ")" => if let Some(open) = opens.pop() {
+                if is_redundant(tokens, open, index) {
+                    return Some((open, index));
+                }
+            },
+            _ => {},
+        }
+    }
+
+    None
+}
+
+fn is_redundant(tokens: &[String], open: usize, close: usize) -> bool {
+    let content = &tokens[open + 1..close];
+    let before = open.checked_sub(1).map(|i| tokens[i].as_str());
+    let after = tokens.get(close + 1).map(String::as_str);
+
+    let mut depth = 0;
+    let mut top_level = Vec::new();
+    for token in content {
+        match token.as_str() {
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            token if depth == 0 => top_level.push(token),
+            _ => {},
+        }
+    }
+
+    let is_boundary = |token: Option<&str>| token.is_none_or(|t| EXPRESSION_BOUNDARIES.contains(&t));
+    let binds_looser_than_neighbour = top_level.contains(&"or") && (before == Some("and") || after == Some("and"));
+
+    !content.is_empty() &&
+        !matches!(content[0].as_str(), "select" | "values" | "with") &&
+        !top_level.contains(&",") &&
+        !binds_looser_than_neighbour &&
+        is_boundary(before) && is_boundary(after)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_sql() {
+        let left = "SELECT a.id,\n    a.name\n   FROM test.account a\n  WHERE ((a.active = true) AND (a.name <> ''::text));";
+        let right = "select A.ID, a.name -- the display name\nfrom test.account a where a.active = TRUE and a.name <> ''::text;";
+
+        assert_eq!(normalize_sql(left), normalize_sql(right));
+        assert_eq!(normalize_sql(&format!("AS $function${}$function$", left)), normalize_sql(&format!("as $$ {} $$", right)));
+    }
+
+    #[test]
+    fn test_normalize_sql_keeps_meaningful_differences() {
+        assert_ne!(normalize_sql("SELECT 'A'"), normalize_sql("SELECT 'a'"));
+        assert_ne!(normalize_sql("SELECT \"A\" FROM t"), normalize_sql("SELECT \"a\" FROM t"));
+        assert_ne!(normalize_sql("WHERE (a OR b) AND c"), normalize_sql("WHERE a OR b AND c"));
+        assert_ne!(normalize_sql("SELECT (a + b) * 2"), normalize_sql("SELECT a + b * 2"));
+    }
+}
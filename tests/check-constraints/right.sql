@@ -0,0 +1,8 @@
+CREATE TABLE check_table (
+    id integer NOT NULL,
+    quantity integer NOT NULL,
+    price numeric,
+    CONSTRAINT check_unchanged CHECK (quantity >= 0),
+    CONSTRAINT check_changed CHECK (price >= 0 AND price < 1000),
+    CONSTRAINT check_added CHECK (quantity < 100)
+);
@@ -0,0 +1,7 @@
+CREATE TABLE event (id integer NOT NULL, created_at date NOT NULL) PARTITION BY RANGE (created_at);
+CREATE TABLE event_2024_01 PARTITION OF event FOR VALUES FROM ('2024-01-01') TO ('2024-02-01');
+CREATE TABLE event_2024_02 PARTITION OF event FOR VALUES FROM ('2024-02-01') TO ('2024-03-01');
+CREATE TABLE event_2024_03 PARTITION OF event FOR VALUES FROM ('2024-03-01') TO ('2024-03-15');
+CREATE TABLE event_old PARTITION OF event FOR VALUES FROM ('2023-01-01') TO ('2024-01-01');
+
+CREATE TABLE region (id integer NOT NULL, code text NOT NULL);
@@ -0,0 +1,20 @@
+CREATE TABLE emit_table_removed (id integer PRIMARY KEY);
+
+CREATE TABLE emit_table_changed (
+    id integer PRIMARY KEY,
+    a text NOT NULL,
+    b integer,
+    c integer
+);
+
+CREATE INDEX emit_index_changed ON emit_table_changed (b);
+
+CREATE SEQUENCE emit_sequence_changed INCREMENT BY 1;
+
+CREATE VIEW emit_view_removed AS SELECT id FROM emit_table_changed;
+
+CREATE TYPE emit_type_removed AS (a integer);
+
+CREATE TYPE emit_enum_changed AS ENUM ('a');
+
+CREATE DOMAIN emit_domain_changed AS integer DEFAULT 1 CONSTRAINT emit_domain_check CHECK (VALUE > 0);
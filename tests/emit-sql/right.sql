@@ -0,0 +1,23 @@
+CREATE TABLE emit_table_added (
+    id integer GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    name text NOT NULL DEFAULT 'unknown'
+);
+
+CREATE TABLE emit_table_changed (
+    id integer PRIMARY KEY,
+    a integer,
+    b integer NOT NULL DEFAULT 0,
+    d integer UNIQUE
+);
+
+CREATE INDEX emit_index_changed ON emit_table_changed (a, b);
+
+CREATE SEQUENCE emit_sequence_changed INCREMENT BY 5;
+
+CREATE FUNCTION emit_routine_added(a integer) RETURNS integer LANGUAGE sql AS $$ SELECT a + 1 $$;
+
+CREATE TYPE emit_type_added AS (a integer, b text COLLATE "C");
+
+CREATE TYPE emit_enum_changed AS ENUM ('a', 'it''s');
+
+CREATE DOMAIN emit_domain_changed AS integer NOT NULL CONSTRAINT emit_domain_check CHECK (VALUE > 1);
@@ -0,0 +1,7 @@
+CREATE EXTENSION citext VERSION '1.5';
+CREATE EXTENSION pgcrypto;
+CREATE EXTENSION seg;
+
+CREATE TABLE account (id integer PRIMARY KEY, email citext NOT NULL);
+
+CREATE FUNCTION account_count() RETURNS bigint LANGUAGE sql AS 'SELECT count(*) FROM account';
@@ -0,0 +1,6 @@
+CREATE TABLE flyway_schema_history (installed_rank integer PRIMARY KEY, version text);
+CREATE TABLE _tmp_import (id integer);
+CREATE TABLE account (id integer PRIMARY KEY, name text);
+CREATE INDEX account_name_tmp ON account (name);
+CREATE FUNCTION pgp_stub(a text) RETURNS text LANGUAGE sql AS 'SELECT a';
+CREATE FUNCTION account_count() RETURNS bigint LANGUAGE sql AS 'SELECT count(*) FROM test.account';
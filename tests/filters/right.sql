@@ -0,0 +1,4 @@
+CREATE TABLE flyway_schema_history (installed_rank integer PRIMARY KEY, version text, description text);
+CREATE TABLE account (id integer PRIMARY KEY, name text, email text);
+CREATE FUNCTION pgp_stub(a text) RETURNS text LANGUAGE sql AS 'SELECT upper(a)';
+CREATE FUNCTION account_count() RETURNS bigint LANGUAGE sql AS 'SELECT count(*) FROM test.account';
@@ -0,0 +1,10 @@
+CREATE TABLE customer (id integer PRIMARY KEY, code text, region text, CONSTRAINT customer_key UNIQUE (code, region));
+CREATE TABLE supplier (id integer PRIMARY KEY);
+
+CREATE TABLE purchase (
+    id integer PRIMARY KEY,
+    party_id integer,
+    customer_id integer,
+    CONSTRAINT purchase_party_fk FOREIGN KEY (party_id) REFERENCES customer (id),
+    CONSTRAINT purchase_customer_fk FOREIGN KEY (customer_id) REFERENCES customer (id)
+);
@@ -0,0 +1,3 @@
+CREATE TABLE account (id integer, name text);
+CREATE SEQUENCE invoice_number;
+CREATE FUNCTION account_count() RETURNS bigint LANGUAGE sql AS 'SELECT count(*) FROM test.account';
@@ -0,0 +1,3 @@
+CREATE TABLE account (id integer, name text COLLATE "C");
+CREATE SEQUENCE invoice_number INCREMENT BY 10;
+CREATE FUNCTION account_count() RETURNS bigint LANGUAGE sql SECURITY DEFINER AS 'SELECT count(*) FROM test.account';
@@ -0,0 +1,6 @@
+CREATE TABLE base (id integer NOT NULL, created_at timestamp);
+CREATE TABLE extra (note text);
+
+CREATE TABLE child (name text) INHERITS (base);
+CREATE TABLE legacy (id integer NOT NULL, created_at timestamp, label text);
+CREATE TABLE detached (flag boolean) INHERITS (base, extra);
@@ -0,0 +1,7 @@
+CREATE TABLE base (id integer NOT NULL, created_at timestamp);
+CREATE TABLE extra (note text);
+
+CREATE TABLE child (name text) INHERITS (base, extra);
+CREATE TABLE legacy (label text) INHERITS (base);
+CREATE TABLE detached (flag boolean) INHERITS (base);
+CREATE TABLE added (code text) INHERITS (base);
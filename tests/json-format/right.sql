@@ -0,0 +1,5 @@
+CREATE TABLE json_table_added (
+    id INT
+);
+
+CREATE SEQUENCE json_sequence_added;
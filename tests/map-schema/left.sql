@@ -0,0 +1,4 @@
+CREATE TABLE account (id serial PRIMARY KEY, name text);
+CREATE TABLE invoice (id integer PRIMARY KEY, account_id integer REFERENCES account (id));
+CREATE VIEW account_names AS SELECT name FROM account;
+CREATE FUNCTION account_count() RETURNS bigint LANGUAGE sql AS 'SELECT count(*) FROM test.account';
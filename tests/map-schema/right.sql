@@ -0,0 +1,8 @@
+DROP SCHEMA IF EXISTS template CASCADE;
+CREATE SCHEMA template;
+SET search_path TO template;
+
+CREATE TABLE account (id serial PRIMARY KEY, name text, email text);
+CREATE TABLE invoice (id integer PRIMARY KEY, account_id integer REFERENCES account (id));
+CREATE VIEW account_names AS SELECT name FROM account;
+CREATE FUNCTION account_count() RETURNS bigint LANGUAGE sql AS 'SELECT count(*) FROM template.account';
@@ -0,0 +1,11 @@
+CREATE TABLE sale (id integer PRIMARY KEY, region text, amount numeric);
+
+CREATE MATERIALIZED VIEW matview_unchanged AS SELECT region, count(*) AS sales FROM sale GROUP BY region;
+
+CREATE MATERIALIZED VIEW matview_removed AS SELECT id FROM sale;
+
+CREATE MATERIALIZED VIEW matview_definition_changed AS SELECT region, sum(amount) AS total FROM sale GROUP BY region;
+GRANT SELECT ON matview_definition_changed TO PUBLIC;
+
+CREATE MATERIALIZED VIEW matview_changed AS SELECT id, region FROM sale WITH NO DATA;
+CREATE INDEX matview_changed_removed_idx ON matview_changed (id);
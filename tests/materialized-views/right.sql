@@ -0,0 +1,13 @@
+CREATE TABLE sale (id integer PRIMARY KEY, region text, amount numeric);
+
+CREATE MATERIALIZED VIEW matview_unchanged AS SELECT region, count(*) AS sales FROM sale GROUP BY region;
+
+CREATE MATERIALIZED VIEW matview_added AS SELECT amount FROM sale;
+CREATE INDEX matview_added_idx ON matview_added (amount);
+
+CREATE MATERIALIZED VIEW matview_definition_changed AS SELECT region, avg(amount) AS average FROM sale GROUP BY region;
+GRANT SELECT ON matview_definition_changed TO PUBLIC;
+
+CREATE MATERIALIZED VIEW matview_changed AS SELECT id, region FROM sale;
+CREATE INDEX matview_changed_added_idx ON matview_changed (region);
+GRANT SELECT ON matview_changed TO PUBLIC;
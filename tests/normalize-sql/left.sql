@@ -0,0 +1,18 @@
+CREATE TABLE account (id integer, name text, active boolean);
+
+CREATE FUNCTION active_accounts() RETURNS SETOF account LANGUAGE sql
+    AS $$
+SELECT id, name, active
+  FROM account
+ WHERE ((active = true) AND (name <> ''))
+$$;
+
+CREATE FUNCTION deactivate(account_id integer) RETURNS void LANGUAGE plpgsql
+    AS $$
+BEGIN
+    UPDATE account SET active = false WHERE id = account_id;
+END
+$$;
+
+CREATE FUNCTION page_size(requested integer) RETURNS integer LANGUAGE sql
+    AS 'SELECT least(requested, 100)';
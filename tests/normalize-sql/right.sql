@@ -0,0 +1,18 @@
+CREATE TABLE account (id integer, name text, active boolean);
+
+CREATE FUNCTION active_accounts() RETURNS SETOF account LANGUAGE sql
+    AS $body$
+    -- accounts that can sign in
+    select ID, Name, Active from account where active = TRUE and name <> ''
+$body$;
+
+CREATE FUNCTION deactivate(account_id integer) RETURNS void LANGUAGE plpgsql
+    AS $$
+begin
+    /* soft delete */
+    update account set active = FALSE where (id = account_id);
+end
+$$;
+
+CREATE FUNCTION page_size(requested integer) RETURNS integer LANGUAGE sql
+    AS 'SELECT least(requested, 50)';
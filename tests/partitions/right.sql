@@ -0,0 +1,10 @@
+CREATE TABLE event (id integer NOT NULL, created_at date NOT NULL) PARTITION BY RANGE (created_at);
+CREATE TABLE event_2024_01 PARTITION OF event FOR VALUES FROM ('2024-01-01') TO ('2024-02-01');
+CREATE TABLE event_2024_02 PARTITION OF event FOR VALUES FROM ('2024-02-01') TO ('2024-03-01');
+CREATE TABLE event_2024_03 PARTITION OF event FOR VALUES FROM ('2024-03-01') TO ('2024-04-01');
+CREATE TABLE event_2024_04 PARTITION OF event FOR VALUES FROM ('2024-04-01') TO ('2024-05-01') PARTITION BY HASH (id);
+CREATE TABLE event_2024_04_0 PARTITION OF event_2024_04 FOR VALUES WITH (MODULUS 2, REMAINDER 0);
+CREATE TABLE event_2024_04_1 PARTITION OF event_2024_04 FOR VALUES WITH (MODULUS 2, REMAINDER 1);
+
+CREATE TABLE region (id integer NOT NULL, code text NOT NULL) PARTITION BY LIST (code);
+CREATE TABLE region_default PARTITION OF region DEFAULT;
@@ -0,0 +1,11 @@
+CREATE FUNCTION normalise(value text) RETURNS text LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+    AS 'SELECT lower(trim(value))';
+
+CREATE FUNCTION lookup(key integer) RETURNS SETOF integer LANGUAGE sql STABLE ROWS 10
+    AS 'SELECT key';
+
+CREATE FUNCTION secret() RETURNS integer LANGUAGE sql
+    AS 'SELECT 1';
+
+CREATE FUNCTION tuned(a integer) RETURNS integer LANGUAGE sql COST 100
+    AS 'SELECT a';
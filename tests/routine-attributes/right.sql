@@ -0,0 +1,11 @@
+CREATE FUNCTION normalise(value text) RETURNS text LANGUAGE sql VOLATILE PARALLEL UNSAFE
+    AS 'SELECT lower(trim(value))';
+
+CREATE FUNCTION lookup(key integer) RETURNS SETOF integer LANGUAGE sql STABLE ROWS 1000
+    AS 'SELECT key';
+
+CREATE FUNCTION secret() RETURNS integer LANGUAGE sql SECURITY DEFINER LEAKPROOF SET search_path = pg_catalog, pg_temp
+    AS 'SELECT 1';
+
+CREATE FUNCTION tuned(a integer) RETURNS integer LANGUAGE sql COST 5000 SET work_mem = '64MB' SET statement_timeout = '5s'
+    AS 'SELECT a';
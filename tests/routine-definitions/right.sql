@@ -0,0 +1,6 @@
+CREATE FUNCTION page_size(requested integer DEFAULT 50) RETURNS integer LANGUAGE sql
+    AS 'SELECT least(requested, 100)';
+
+CREATE FUNCTION reformatted(a integer) RETURNS integer LANGUAGE sql
+    AS 'SELECT   a
+            + 1';
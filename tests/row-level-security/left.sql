@@ -0,0 +1,11 @@
+CREATE TABLE document (id integer PRIMARY KEY, tenant_id integer NOT NULL, owner text);
+ALTER TABLE document ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY policy_unchanged ON document FOR SELECT USING (owner = current_user);
+CREATE POLICY policy_changed ON document FOR ALL TO PUBLIC
+    USING (tenant_id = current_setting('app.tenant_id')::integer);
+CREATE POLICY policy_removed ON document AS RESTRICTIVE FOR DELETE USING (false);
+
+CREATE TABLE audit (id integer PRIMARY KEY);
+ALTER TABLE audit ENABLE ROW LEVEL SECURITY;
+ALTER TABLE audit FORCE ROW LEVEL SECURITY;
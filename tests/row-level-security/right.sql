@@ -0,0 +1,10 @@
+CREATE TABLE document (id integer PRIMARY KEY, tenant_id integer NOT NULL, owner text);
+ALTER TABLE document ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY policy_unchanged ON document FOR SELECT USING (owner = current_user);
+CREATE POLICY policy_changed ON document FOR ALL TO PUBLIC
+    USING (true)
+    WITH CHECK (tenant_id = current_setting('app.tenant_id')::integer);
+CREATE POLICY policy_added ON document FOR INSERT TO postgres WITH CHECK (owner IS NOT NULL);
+
+CREATE TABLE audit (id integer PRIMARY KEY);
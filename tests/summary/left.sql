@@ -0,0 +1,6 @@
+CREATE TABLE account (id integer PRIMARY KEY, name text, email text);
+CREATE INDEX account_name ON account (name);
+CREATE TABLE audit (id integer);
+CREATE SEQUENCE invoice_number;
+CREATE VIEW account_names AS SELECT name FROM account;
+CREATE FUNCTION account_count() RETURNS bigint LANGUAGE sql AS 'SELECT count(*) FROM test.account';
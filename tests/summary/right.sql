@@ -0,0 +1,8 @@
+CREATE TABLE account (id integer PRIMARY KEY, name varchar(100) NOT NULL, created timestamp);
+CREATE INDEX account_name ON account (lower(name));
+CREATE TABLE ledger (id integer);
+GRANT SELECT ON ledger TO PUBLIC;
+CREATE SEQUENCE invoice_number INCREMENT BY 10;
+CREATE VIEW account_names AS SELECT name FROM account;
+CREATE FUNCTION account_count() RETURNS bigint LANGUAGE sql AS 'SELECT count(*) FROM test.account';
+GRANT SELECT ON account TO PUBLIC;
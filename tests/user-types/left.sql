@@ -0,0 +1,22 @@
+CREATE TYPE type_unchanged AS ENUM ('a', 'b');
+
+CREATE TYPE type_removed AS ENUM ('a');
+
+CREATE TYPE enum_changed AS ENUM ('low', 'high');
+
+CREATE TYPE composite_changed AS (
+    attribute_unchanged integer,
+    attribute_changed integer,
+    attribute_removed text
+);
+
+CREATE DOMAIN domain_changed AS integer
+    DEFAULT 1
+    CONSTRAINT constraint_unchanged CHECK (VALUE < 1000)
+    CONSTRAINT constraint_changed CHECK (VALUE > 0)
+    CONSTRAINT constraint_removed CHECK (VALUE <> 5);
+
+CREATE TYPE range_changed AS RANGE (SUBTYPE = integer);
+
+CREATE TYPE privilege_changed AS ENUM ('a');
+REVOKE USAGE ON TYPE privilege_changed FROM PUBLIC;
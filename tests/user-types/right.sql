@@ -0,0 +1,21 @@
+CREATE TYPE type_unchanged AS ENUM ('a', 'b');
+
+CREATE TYPE type_added AS (a integer, b text);
+
+CREATE TYPE enum_changed AS ENUM ('low', 'medium', 'high');
+
+CREATE TYPE composite_changed AS (
+    attribute_unchanged integer,
+    attribute_changed bigint,
+    attribute_added text
+);
+
+CREATE DOMAIN domain_changed AS integer
+    NOT NULL
+    CONSTRAINT constraint_unchanged CHECK (VALUE < 1000)
+    CONSTRAINT constraint_changed CHECK (VALUE > 1)
+    CONSTRAINT constraint_added CHECK (VALUE <> 6);
+
+CREATE TYPE range_changed AS RANGE (SUBTYPE = bigint);
+
+CREATE TYPE privilege_changed AS ENUM ('a');